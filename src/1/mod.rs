@@ -0,0 +1,66 @@
+//! Day 1: Sonar Sweep
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+pub fn parse(s: &str) -> Result<Vec<u32>, ParseError> {
+    Scanner::lines(s)
+        .map(|mut l| {
+            let n = l.number::<u32>()?;
+            l.end()?;
+            Ok(n)
+        })
+        .collect()
+}
+
+/// Counts the measurements that are larger than the previous one.
+pub fn part1(nums: &[u32]) -> usize {
+    nums.windows(2).filter(|w| w[0] < w[1]).count()
+}
+
+/// Counts the three-measurement windows that are larger than the previous
+/// one. Two neighbouring windows share two measurements, so only the ones
+/// that differ need to be compared.
+pub fn part2(nums: &[u32]) -> usize {
+    nums.windows(4).filter(|w| w[0] < w[3]).count()
+}
+
+/// Generates `size` depth measurements that mostly increase.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    let mut depth: i64 = 100;
+
+    for _ in 0..size {
+        depth = (depth + rng.between(-10, 20)).max(0);
+        s += &format!("{}\n", depth);
+    }
+
+    s
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Vec<u32>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<u32>, _params: &Params) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<u32>, _params: &Params) -> usize {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,210 @@
+//! Day 10: Syntax Scoring
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::error::Error;
+use std::fmt;
+
+pub fn parse(s: &str) -> Result<Vec<String>, ParseError> {
+    Scanner::lines(s)
+        .map(|mut l| {
+            let chunks = l.take_while(|c| "()[]{}<>".contains(c));
+            l.end().map_err(|_| l.error("a bracket"))?;
+            Ok(chunks.to_string())
+        })
+        .collect()
+}
+
+pub fn part1(lines: &[String]) -> u32 {
+    let mut score = 0;
+    for l in lines {
+        let mut stack = Vec::new();
+
+        for c in l.chars() {
+            match c {
+                '(' => {
+                    stack.push(')');
+                }
+                '[' => {
+                    stack.push(']');
+                }
+                '{' => {
+                    stack.push('}');
+                }
+                '<' => {
+                    stack.push('>');
+                }
+                ')' | ']' | '}' | '>' => {
+                    // a closer without an opener is corrupt too
+                    let last = stack.pop();
+                    if last != Some(c) {
+                        // syntax error
+                        score += match c {
+                            ')' => 3,
+                            ']' => 57,
+                            '}' => 1197,
+                            '>' => 25137,
+                            _ => 0,
+                        };
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    score
+}
+
+/// Why the incomplete lines have no middle score.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unscored {
+    /// Every line is corrupt or complete.
+    NoneIncomplete,
+    /// The completion score of a line, counted from 1, doesn't fit.
+    TooLong { line: usize },
+}
+
+impl fmt::Display for Unscored {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unscored::NoneIncomplete => write!(f, "no line is incomplete"),
+            Unscored::TooLong { line } => {
+                write!(f, "the completion score of line {} overflows u64", line)
+            }
+        }
+    }
+}
+
+impl Error for Unscored {}
+
+pub fn part2(lines: &[String]) -> Result<u64, Unscored> {
+    let mut scores = Vec::new();
+
+    for (i, l) in lines.iter().enumerate() {
+        let mut stack = Vec::new();
+        let mut invalid = false;
+
+        for c in l.chars() {
+            match c {
+                '(' => {
+                    stack.push(')');
+                }
+                '[' => {
+                    stack.push(']');
+                }
+                '{' => {
+                    stack.push('}');
+                }
+                '<' => {
+                    stack.push('>');
+                }
+                ')' | ']' | '}' | '>' => {
+                    // a closer without an opener is corrupt too
+                    let last = stack.pop();
+                    if last != Some(c) {
+                        invalid = true;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !invalid && !stack.is_empty() {
+            let mut score: u64 = 0;
+            for c in stack.into_iter().rev() {
+                let points = match c {
+                    ')' => 1,
+                    ']' => 2,
+                    '}' => 3,
+                    '>' => 4,
+                    _ => panic!(),
+                };
+                score = score
+                    .checked_mul(5)
+                    .and_then(|s| s.checked_add(points))
+                    .ok_or(Unscored::TooLong { line: i + 1 })?;
+            }
+            scores.push(score);
+        }
+    }
+
+    if scores.is_empty() {
+        return Err(Unscored::NoneIncomplete);
+    }
+
+    scores.sort_unstable();
+    Ok(scores[scores.len() / 2])
+}
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Generates `size` lines. About half are corrupted and the others are
+/// incomplete, and the first one is always incomplete.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for i in 0..size.max(1) {
+        let mut closing = Vec::new();
+
+        for _ in 0..rng.between(10, 60) {
+            // Keep the completion scores within u64
+            if !closing.is_empty() && (closing.len() >= 20 || rng.chance(0.4)) {
+                s.push(closing.pop().unwrap());
+            } else {
+                let (open, close) = *rng.choose(&PAIRS);
+                s.push(open);
+                closing.push(close);
+            }
+        }
+
+        if closing.is_empty() {
+            let (open, close) = *rng.choose(&PAIRS);
+            s.push(open);
+            closing.push(close);
+        }
+
+        if i > 0 && rng.chance(0.5) {
+            let expected = closing[closing.len() - 1];
+            let wrong: Vec<char> = PAIRS
+                .iter()
+                .map(|&(_, c)| c)
+                .filter(|&c| c != expected)
+                .collect();
+            s.push(*rng.choose(&wrong));
+        }
+
+        s.push('\n');
+    }
+
+    s
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = Result<u64, Unscored>;
+
+    fn parse(s: &str) -> Result<Vec<String>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<String>, _params: &Params) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<String>, _params: &Params) -> Result<u64, Unscored> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
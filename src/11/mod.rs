@@ -0,0 +1,292 @@
+//! Day 11: Dumbo Octopus
+
+use crate::animate::{self, Frames};
+use crate::gif::Movie;
+use crate::grid::Grid;
+use crate::image::Rgb;
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct Cavern {
+    octos: Grid<u8>,
+}
+
+impl Cavern {
+    pub fn new(octos: Grid<u8>) -> Self {
+        Self { octos }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        self.octos.get((x, y)).copied()
+    }
+
+    /// The energy levels of all octopuses.
+    pub fn octos(&self) -> &Grid<u8> {
+        &self.octos
+    }
+
+    /// The number of octopuses.
+    pub fn len(&self) -> usize {
+        self.octos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.octos.is_empty()
+    }
+
+    /// Performs a timestep and returns the number of flashes.
+    pub fn step(&mut self) -> usize {
+        let mut flash_map = Grid::filled(self.octos.width(), self.octos.height(), false);
+
+        for e in self.octos.iter_mut() {
+            *e += 1;
+        }
+
+        let mut new_flashes = true;
+        while new_flashes {
+            new_flashes = false;
+            for p in self.octos.positions() {
+                if self.octos[p] > 9 && !flash_map[p] {
+                    // only flash if we have enough energy and haven't
+                    // flashed in this iteration
+                    flash_map[p] = true;
+                    new_flashes = true;
+
+                    for n in flash_map.neighbors8(p) {
+                        self.octos[n] += 1;
+                    }
+                }
+            }
+        }
+
+        // reset all that have flashed to 0
+        for p in flash_map.positions() {
+            if flash_map[p] {
+                self.octos[p] = 0;
+            }
+        }
+
+        flash_map.iter().filter(|&&f| f).count()
+    }
+}
+
+impl fmt::Display for Cavern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let s = self.octos.display_with(|&v| match v {
+            0 => '.',
+            v => char::from_digit(v.into(), 10).unwrap_or('*'),
+        });
+
+        write!(f, "{}", s)
+    }
+}
+
+pub fn parse(s: &str) -> Result<Cavern, ParseError> {
+    Ok(Cavern::new(Grid::parse_digits(s)?))
+}
+
+pub const STEPS: Param = Param {
+    name: "steps",
+    default: 100,
+    min: 0,
+    max: 1_000_000,
+    help: "steps simulated in part 1",
+};
+
+/// Returns the total number of flashes during the given number of steps.
+pub fn flashes(cavern: &Cavern, steps: usize) -> usize {
+    let mut c = cavern.clone();
+
+    (0..steps).map(|_| c.step()).sum()
+}
+
+pub const MAX_STEPS: Param = Param {
+    name: "max-steps",
+    default: 10_000,
+    min: 1,
+    max: 1_000_000,
+    help: "steps simulated in part 2 before giving up",
+};
+
+pub const MAX_FRAMES: Param = Param {
+    name: "max-frames",
+    default: 1000,
+    min: 1,
+    max: 1_000_000,
+    help: "steps shown by animate and recorded by gif at most",
+};
+
+/// The octopuses don't all flash at once within the steps simulated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unsynchronized {
+    pub steps: usize,
+}
+
+impl fmt::Display for Unsynchronized {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the octopuses don't all flash at once within {} steps",
+            self.steps
+        )
+    }
+}
+
+impl Error for Unsynchronized {}
+
+pub fn part1(cavern: &Cavern) -> usize {
+    flashes(cavern, STEPS.default as usize)
+}
+
+/// Returns the first step during which all octopuses flash, trying up to
+/// `max_steps` steps.
+pub fn synchronized(cavern: &Cavern, max_steps: usize) -> Result<usize, Unsynchronized> {
+    let mut c = cavern.clone();
+
+    (1..=max_steps)
+        .find(|_| c.step() == c.len())
+        .ok_or(Unsynchronized { steps: max_steps })
+}
+
+pub fn part2(cavern: &Cavern) -> Result<usize, Unsynchronized> {
+    synchronized(cavern, MAX_STEPS.default as usize)
+}
+
+/// Draws the energy levels with the octopuses that just flashed highlighted.
+pub fn render(cavern: &Cavern) -> String {
+    let mut s = String::new();
+
+    for row in cavern.octos.rows() {
+        for &v in row {
+            if v == 0 {
+                s += &animate::highlight("0");
+            } else {
+                s += &v.to_string();
+            }
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+/// The cavern at the start and after every step until all octopuses flash
+/// at once, or up to `max_steps` steps, together with the number of flashes
+/// during the step.
+pub fn history(cavern: &Cavern, max_steps: usize) -> impl Iterator<Item = (usize, Cavern)> {
+    let mut c = cavern.clone();
+    let mut synchronized = false;
+
+    std::iter::once((0, cavern.clone())).chain(
+        std::iter::from_fn(move || {
+            if synchronized {
+                return None;
+            }
+
+            let flashes = c.step();
+            synchronized = flashes == c.len();
+
+            Some((flashes, c.clone()))
+        })
+        .take(max_steps),
+    )
+}
+
+/// The cavern after every step until all octopuses flash at once, or up to
+/// `max_steps` steps.
+pub fn frames(cavern: &Cavern, max_steps: usize) -> Frames<'static> {
+    let mut total = 0;
+
+    Box::new(
+        history(cavern, max_steps)
+            .enumerate()
+            .map(move |(step, (flashes, c))| {
+                total += flashes;
+                format!(
+                    "step {}  flashes {}  total {}\n{}",
+                    step,
+                    flashes,
+                    total,
+                    render(&c)
+                )
+            }),
+    )
+}
+
+/// The colours of the energy levels in `movie`, with the flashes in yellow.
+const PALETTE: [Rgb; 10] = [
+    [255, 220, 60],
+    [10, 20, 50],
+    [15, 30, 70],
+    [20, 40, 90],
+    [25, 50, 110],
+    [30, 60, 130],
+    [35, 70, 150],
+    [40, 80, 170],
+    [45, 90, 190],
+    [50, 100, 210],
+];
+
+/// The cavern after every step until all octopuses flash at once, or up to
+/// `max_steps` steps, with one colour per energy level.
+pub fn movie(cavern: &Cavern, max_steps: usize) -> Movie {
+    Movie {
+        palette: PALETTE.to_vec(),
+        frames: history(cavern, max_steps).map(|(_, c)| c.octos).collect(),
+    }
+}
+
+/// Generates a `size` by `size` cavern. The octopuses of most caverns never
+/// all flash at once, so part 2 usually gives up after `MAX_STEPS`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            s += &rng.between(0, 9).to_string();
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Cavern;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, Unsynchronized>;
+
+    const PARAMS: &'static [Param] = &[STEPS, MAX_STEPS, MAX_FRAMES];
+
+    fn parse(s: &str) -> Result<Cavern, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Cavern, params: &Params) -> usize {
+        flashes(input, params.get(&STEPS) as usize)
+    }
+
+    fn part2(input: &Cavern, params: &Params) -> Result<usize, Unsynchronized> {
+        synchronized(input, params.get(&MAX_STEPS) as usize)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn frames<'a>(input: &'a Cavern, params: &Params) -> Option<Frames<'a>> {
+        Some(frames(input, params.get(&MAX_FRAMES) as usize))
+    }
+
+    fn movie(input: &Cavern, params: &Params) -> Option<Movie> {
+        Some(movie(input, params.get(&MAX_FRAMES) as usize))
+    }
+}
@@ -0,0 +1,5 @@
+use aoc2021::{day12::Day12, solution};
+
+fn main() {
+    solution::main::<Day12>();
+}
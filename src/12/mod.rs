@@ -0,0 +1,260 @@
+//! Day 12: Passage Pathing
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+/// The caves and the passages between them. Caves are numbered in the order
+/// they first appear in the input.
+#[derive(Clone, Debug)]
+pub struct Caves {
+    names: Vec<String>,
+    /// Whether a cave is big and can be visited any number of times.
+    big: Vec<bool>,
+    neighbours: Vec<Vec<usize>>,
+    start: usize,
+    end: usize,
+}
+
+impl Caves {
+    /// The number of a cave, which is added if it is new.
+    fn id(&mut self, name: &str) -> usize {
+        match self.names.iter().position(|n| n == name) {
+            Some(id) => id,
+            None => {
+                self.names.push(name.to_string());
+                self.big.push(is_big(name));
+                self.neighbours.push(Vec::new());
+                self.names.len() - 1
+            }
+        }
+    }
+
+    fn connect(&mut self, a: usize, b: usize) {
+        if !self.neighbours[a].contains(&b) {
+            self.neighbours[a].push(b);
+            self.neighbours[b].push(a);
+        }
+    }
+}
+
+fn is_big(name: &str) -> bool {
+    name.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// Reads the name of a cave, which is all upper or all lower case.
+fn cave<'a>(l: &mut Scanner<'a>) -> Result<&'a str, ParseError> {
+    let start = l.clone();
+    let name = l.take_while(|c| c.is_ascii_alphabetic());
+
+    if name.is_empty() || !(is_big(name) || name.chars().all(|c| c.is_ascii_lowercase())) {
+        return Err(start.error("a cave name in upper or lower case"));
+    }
+
+    Ok(name)
+}
+
+/// The caves are limited to 64 to keep the visited ones in a bit set.
+const MAX_CAVES: usize = 64;
+
+pub fn parse(s: &str) -> Result<Caves, ParseError> {
+    let mut caves = Caves {
+        names: Vec::new(),
+        big: Vec::new(),
+        neighbours: Vec::new(),
+        start: 0,
+        end: 0,
+    };
+
+    for mut l in Scanner::lines(s) {
+        let a = cave(&mut l)?;
+        l.tag("-")?;
+        let second = l.clone();
+        let b = cave(&mut l)?;
+        l.end()?;
+
+        // two connected big caves would allow endless paths
+        if is_big(a) && is_big(b) {
+            return Err(second.error("a small cave next to a big one"));
+        }
+        if a == b {
+            return Err(second.error("another cave"));
+        }
+
+        let (a, b) = (caves.id(a), caves.id(b));
+        caves.connect(a, b);
+
+        if caves.names.len() > MAX_CAVES {
+            return Err(second.error(format!("at most {} caves", MAX_CAVES)));
+        }
+    }
+
+    let lines = s.lines().count();
+    let find = |name: &str| {
+        caves
+            .names
+            .iter()
+            .position(|n| n == name)
+            .ok_or_else(|| ParseError::end_of_input(lines, format!("a passage to {}", name)))
+    };
+    caves.start = find("start")?;
+    caves.end = find("end")?;
+
+    Ok(caves)
+}
+
+/// The number of paths from `cave` to the end that don't enter the small
+/// caves in `visited` again, except for one that isn't the start if `twice`.
+fn count(
+    caves: &Caves,
+    cave: usize,
+    visited: u64,
+    twice: bool,
+    memo: &mut HashMap<(usize, u64, bool), u64>,
+) -> u64 {
+    if cave == caves.end {
+        return 1;
+    }
+    if let Some(&n) = memo.get(&(cave, visited, twice)) {
+        return n;
+    }
+
+    let mut n = 0;
+    for &next in &caves.neighbours[cave] {
+        let bit = 1 << next;
+
+        n += if caves.big[next] {
+            count(caves, next, visited, twice, memo)
+        } else if visited & bit == 0 {
+            count(caves, next, visited | bit, twice, memo)
+        } else if twice && next != caves.start {
+            count(caves, next, visited, false, memo)
+        } else {
+            0
+        };
+    }
+
+    memo.insert((cave, visited, twice), n);
+    n
+}
+
+/// Counts the paths from the start to the end that visit small caves at
+/// most once, except for one small cave other than the start that may be
+/// visited twice if `twice`. The number of ways on from a cave only depends
+/// on the small caves visited so far and whether the second visit is used,
+/// so it is memoised for those.
+pub fn paths(caves: &Caves, twice: bool) -> u64 {
+    let mut memo = HashMap::new();
+
+    count(caves, caves.start, 1 << caves.start, twice, &mut memo)
+}
+
+/// Walks every path one by one. The number of paths grows exponentially, so
+/// this serves as a reference for `paths` on small cave systems.
+pub fn paths_naive(caves: &Caves, twice: bool) -> u64 {
+    let mut stack = vec![(caves.start, vec![caves.start], twice)];
+    let mut paths = 0;
+
+    while let Some((cave, path, twice)) = stack.pop() {
+        if cave == caves.end {
+            paths += 1;
+            continue;
+        }
+
+        for &next in &caves.neighbours[cave] {
+            let again = !caves.big[next] && path.contains(&next);
+            if again && (!twice || next == caves.start) {
+                continue;
+            }
+
+            let mut path = path.clone();
+            path.push(next);
+            stack.push((next, path, twice && !again));
+        }
+    }
+
+    paths
+}
+
+pub fn part1(caves: &Caves) -> u64 {
+    paths(caves, false)
+}
+
+pub fn part2(caves: &Caves) -> u64 {
+    paths(caves, true)
+}
+
+/// Generates a cave system with up to 12 small and 3 big caves and about
+/// `size` passages, in which every cave can be reached from the start.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let small = (size / 2).clamp(2, 12);
+    let big = (small / 4 + 1).min(3);
+
+    let mut names = vec![String::from("start")];
+    while names.len() < 1 + small + big {
+        let letters = if names.len() <= small { b'a' } else { b'A' };
+        let name: String = (0..2)
+            .map(|_| (letters + rng.below(26) as u8) as char)
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names.push(String::from("end"));
+    let big = |i: usize| is_big(&names[i]);
+
+    // every cave is connected to one before it first, so all are reachable
+    let mut passages: Vec<(usize, usize)> = Vec::new();
+    for i in 1..names.len() {
+        let earlier: Vec<usize> = (0..i).filter(|&j| !(big(i) && big(j))).collect();
+        passages.push((*rng.choose(&earlier), i));
+    }
+
+    for _ in 0..size * 10 {
+        if passages.len() >= size {
+            break;
+        }
+
+        let (a, b) = (rng.below(names.len()), rng.below(names.len()));
+        let known = passages.contains(&(a, b)) || passages.contains(&(b, a));
+        if a != b && !(big(a) && big(b)) && !known {
+            passages.push((a, b));
+        }
+    }
+
+    rng.shuffle(&mut passages);
+    passages
+        .iter()
+        .map(|&(a, b)| {
+            let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            format!("{}-{}\n", names[a], names[b])
+        })
+        .collect()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Caves;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(s: &str) -> Result<Caves, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Caves, _params: &Params) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Caves, _params: &Params) -> u64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
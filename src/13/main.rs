@@ -0,0 +1,5 @@
+use aoc2021::{day13::Day13, solution};
+
+fn main() {
+    solution::main::<Day13>();
+}
@@ -0,0 +1,309 @@
+//! Day 13: Transparent Origami
+
+use crate::grid::Grid;
+use crate::image::{self, Image};
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::collections::BTreeSet;
+
+/// A fold of the sheet along a line, which moves the dots behind the line
+/// onto the part in front of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fold {
+    /// Folds the part right of `x` to the left.
+    Left(usize),
+    /// Folds the part below `y` up.
+    Up(usize),
+}
+
+/// The dots on a sheet of transparent paper, which has its top left corner
+/// at (0, 0).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sheet {
+    dots: BTreeSet<(usize, usize)>,
+}
+
+/// The position of a dot at `v` after folding along `line`. Dots on the
+/// line and dots that would end up beyond the edge of the sheet vanish.
+fn mirror(v: usize, line: usize) -> Option<usize> {
+    match v {
+        v if v < line => Some(v),
+        v if v == line => None,
+        v => (2 * line).checked_sub(v),
+    }
+}
+
+impl Sheet {
+    pub fn new(dots: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        Self {
+            dots: dots.into_iter().collect(),
+        }
+    }
+
+    /// The number of visible dots.
+    pub fn len(&self) -> usize {
+        self.dots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    pub fn contains(&self, dot: (usize, usize)) -> bool {
+        self.dots.contains(&dot)
+    }
+
+    pub fn fold(&self, fold: Fold) -> Self {
+        let dots = self.dots.iter().filter_map(|&(x, y)| match fold {
+            Fold::Left(line) => Some((mirror(x, line)?, y)),
+            Fold::Up(line) => Some((x, mirror(y, line)?)),
+        });
+
+        Self::new(dots)
+    }
+
+    /// The sheet from the top left corner to the farthest dots.
+    pub fn grid(&self) -> Grid<bool> {
+        let width = self.dots.iter().map(|d| d.0 + 1).max().unwrap_or(0);
+        let height = self.dots.iter().map(|d| d.1 + 1).max().unwrap_or(0);
+
+        let mut grid = Grid::filled(width, height, false);
+        for &d in &self.dots {
+            grid[d] = true;
+        }
+
+        grid
+    }
+
+    /// Draws the sheet with `#` for dots and `.` for empty places.
+    pub fn render(&self) -> String {
+        self.grid().display_with(|&dot| if dot { '#' } else { '.' })
+    }
+}
+
+/// The letters that the puzzles write with dots, which are 4 dots wide and
+/// 6 high with an empty column between them.
+const FONT_LETTERS: &str = "ABCEFGHIJKLOPRSUZ";
+
+const FONT: &str = "\
+.##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.####
+#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#....#
+#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#...#.
+####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#..#..
+#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#.#...
+#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##..####
+";
+
+const LETTER_WIDTH: usize = 4;
+const LETTER_HEIGHT: usize = 6;
+
+/// The dots of the letter at `index` as a string of `#` and `.`, row by row.
+fn glyph(grid: &Grid<bool>, index: usize) -> String {
+    let left = index * (LETTER_WIDTH + 1);
+
+    (0..LETTER_HEIGHT)
+        .flat_map(|y| (left..left + LETTER_WIDTH).map(move |x| (x, y)))
+        .map(|p| if grid.get(p) == Some(&true) { '#' } else { '.' })
+        .collect()
+}
+
+/// Reads the letters written on a sheet. Returns `None` if the sheet isn't
+/// a line of letters or one of them is unknown.
+pub fn read(sheet: &Sheet) -> Option<String> {
+    let font = Grid::parse_pixels(FONT).expect("the font is valid");
+    let grid = sheet.grid();
+
+    if grid.height() != LETTER_HEIGHT {
+        return None;
+    }
+
+    let letters = (grid.width() + LETTER_WIDTH) / (LETTER_WIDTH + 1);
+    (0..letters)
+        .map(|i| {
+            let dots = glyph(&grid, i);
+            let known = (0..FONT_LETTERS.len()).position(|j| glyph(&font, j) == dots)?;
+            FONT_LETTERS.chars().nth(known)
+        })
+        .collect()
+}
+
+/// Reads one fold instruction, such as `fold along x=5`.
+fn fold(l: &mut Scanner) -> Result<Fold, ParseError> {
+    l.tag("fold along ")?;
+
+    let fold = match l.next_char() {
+        Some('x') => Fold::Left,
+        Some('y') => Fold::Up,
+        _ => return Err(l.error("'x' or 'y'")),
+    };
+    l.tag("=")?;
+    let line = l.number()?;
+    l.end()?;
+
+    Ok(fold(line))
+}
+
+pub fn parse(s: &str) -> Result<(Sheet, Vec<Fold>), ParseError> {
+    let mut lines = Scanner::lines(s);
+    let mut dots = Vec::new();
+
+    for mut l in lines.by_ref() {
+        if l.is_done() {
+            break;
+        }
+
+        let x = l.number()?;
+        l.tag(",")?;
+        let y = l.number()?;
+        l.end()?;
+
+        dots.push((x, y));
+    }
+
+    let folds = lines
+        .map(|mut l| fold(&mut l))
+        .collect::<Result<Vec<_>, _>>()?;
+    if folds.is_empty() {
+        return Err(ParseError::end_of_input(s.lines().count(), "a fold"));
+    }
+
+    Ok((Sheet::new(dots), folds))
+}
+
+/// Folds the sheet along all lines.
+pub fn fold_all((sheet, folds): &(Sheet, Vec<Fold>)) -> Sheet {
+    folds.iter().fold(sheet.clone(), |sheet, &f| sheet.fold(f))
+}
+
+pub fn part1((sheet, folds): &(Sheet, Vec<Fold>)) -> usize {
+    sheet.fold(folds[0]).len()
+}
+
+/// The letters on the folded sheet, or the sheet itself if they can't be
+/// read.
+pub fn part2(manual: &(Sheet, Vec<Fold>)) -> String {
+    let sheet = fold_all(manual);
+
+    read(&sheet).unwrap_or_else(|| sheet.render())
+}
+
+/// The folded sheet with white dots.
+pub fn sheet_image(manual: &(Sheet, Vec<Fold>)) -> Image {
+    fold_all(manual)
+        .grid()
+        .map(|&dot| if dot { image::WHITE } else { image::BLACK })
+}
+
+/// Writes `letters` from the font and unfolds the sheet `folds` times,
+/// alternating up and left. Every unfolding keeps a dot, mirrors it or
+/// both, so folding the sheet in the order of the instructions brings the
+/// letters back. The letters have to be in the font.
+pub fn unfold(rng: &mut Rng, letters: &str, folds: usize) -> String {
+    let font = Grid::parse_pixels(FONT).expect("the font is valid");
+
+    let mut dots = BTreeSet::new();
+    for (i, c) in letters.chars().enumerate() {
+        let index = FONT_LETTERS.find(c).expect("a letter of the font");
+
+        for y in 0..LETTER_HEIGHT {
+            for x in 0..LETTER_WIDTH {
+                if font[(index * (LETTER_WIDTH + 1) + x, y)] {
+                    dots.insert((i * (LETTER_WIDTH + 1) + x, y));
+                }
+            }
+        }
+    }
+
+    let mut width = letters.len() * (LETTER_WIDTH + 1) - 1;
+    let mut height = LETTER_HEIGHT;
+    let mut instructions = Vec::new();
+
+    for i in 0..folds {
+        let fold = if i % 2 == 0 {
+            Fold::Up(height)
+        } else {
+            Fold::Left(width)
+        };
+
+        let mut unfolded = BTreeSet::new();
+        for &(x, y) in &dots {
+            let mirrored = match fold {
+                Fold::Left(line) => (2 * line - x, y),
+                Fold::Up(line) => (x, 2 * line - y),
+            };
+
+            let (keep, mirror) = match rng.below(5) {
+                0 | 1 => (true, false),
+                2 | 3 => (false, true),
+                _ => (true, true),
+            };
+            if keep {
+                unfolded.insert((x, y));
+            }
+            if mirror {
+                unfolded.insert(mirrored);
+            }
+        }
+
+        dots = unfolded;
+        match fold {
+            Fold::Left(line) => width = 2 * line + 1,
+            Fold::Up(line) => height = 2 * line + 1,
+        }
+        instructions.push(fold);
+    }
+
+    let mut dots: Vec<(usize, usize)> = dots.into_iter().collect();
+    rng.shuffle(&mut dots);
+
+    let mut s: String = dots.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect();
+    s.push('\n');
+    for fold in instructions.iter().rev() {
+        s += &match fold {
+            Fold::Left(x) => format!("fold along x={}\n", x),
+            Fold::Up(y) => format!("fold along y={}\n", y),
+        };
+    }
+
+    s
+}
+
+/// Generates a sheet with up to 8 random letters written on it, which is
+/// unfolded 12 times like the puzzle input. `size` is the number of letters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let font: Vec<char> = FONT_LETTERS.chars().collect();
+    let letters: String = (0..size.clamp(1, 8)).map(|_| *rng.choose(&font)).collect();
+
+    unfold(rng, &letters, 12)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = (Sheet, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(s: &str) -> Result<(Sheet, Vec<Fold>), ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &(Sheet, Vec<Fold>), _params: &Params) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &(Sheet, Vec<Fold>), _params: &Params) -> String {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn image(input: &(Sheet, Vec<Fold>), _params: &Params) -> Option<Image> {
+        Some(sheet_image(input))
+    }
+}
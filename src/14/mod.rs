@@ -0,0 +1,344 @@
+//! Day 14: Extended Polymerization
+
+use crate::counter::{Counter, Overflow};
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+#[derive(Hash, Clone, Copy, PartialEq, Eq)]
+pub struct Pair(char, char);
+
+/// Reads one element, which is an uppercase letter.
+fn element(l: &mut Scanner) -> Result<char, ParseError> {
+    match l.peek() {
+        Some(c) if c.is_ascii_uppercase() => {
+            l.next_char();
+            Ok(c)
+        }
+        _ => Err(l.error("an element")),
+    }
+}
+
+impl Pair {
+    fn new(s: &str) -> Self {
+        assert!(s.len() == 2);
+        let mut chars = s.chars();
+        Self(chars.next().unwrap(), chars.next().unwrap())
+    }
+
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        Ok(Self(element(l)?, element(l)?))
+    }
+}
+
+impl fmt::Debug for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}{}", self.0, self.1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    pattern: Pair,
+    /// The two pairs the pattern turns into.
+    produces: [Pair; 2],
+    addition: char,
+}
+
+impl Rule {
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        Rule::parse(&mut Scanner::new(s, 1))
+    }
+
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        let pair = Pair::parse(l)?;
+        l.tag(" -> ")?;
+        let middle = element(l)?;
+        l.end()?;
+
+        Ok(Self {
+            pattern: pair,
+            produces: [Pair(pair.0, middle), Pair(middle, pair.1)],
+            addition: middle,
+        })
+    }
+}
+
+/// Adds `n` to the count of a key, or returns `None` if it overflows.
+fn add<K: Hash + Eq, C: Counter>(counts: &mut HashMap<K, C>, key: K, n: &C) -> Option<()> {
+    let sum = match counts.get(&key) {
+        Some(count) => count.checked_add(n)?,
+        None => n.clone(),
+    };
+    counts.insert(key, sum);
+
+    Some(())
+}
+
+/// The number of times every pair and every element occurs in the polymer.
+#[derive(Clone, Debug)]
+pub struct Polymer<C = u64> {
+    pairs: HashMap<Pair, C>,
+    counts: HashMap<char, C>,
+}
+
+impl Polymer {
+    pub fn new(s: &str) -> Self {
+        let mut pairs = HashMap::new();
+
+        for i in 0..s.len() - 1 {
+            *pairs.entry(Pair::new(&s[i..][..2])).or_insert(0) += 1;
+        }
+
+        let mut counts = HashMap::new();
+        for c in s.chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+
+        Self { pairs, counts }
+    }
+
+    /// The same polymer counted with another type.
+    pub fn to_counter<C: Counter>(&self) -> Polymer<C> {
+        Polymer {
+            pairs: self
+                .pairs
+                .iter()
+                .map(|(&p, &n)| (p, C::from_u64(n)))
+                .collect(),
+            counts: self
+                .counts
+                .iter()
+                .map(|(&c, &n)| (c, C::from_u64(n)))
+                .collect(),
+        }
+    }
+}
+
+impl<C: Counter> Polymer<C> {
+    /// Performs one round of pair insertion. Returns `None` if the counts
+    /// overflow.
+    pub fn step(&self, ruleset: &HashMap<Pair, Rule>) -> Option<Self> {
+        let mut next = Self {
+            pairs: HashMap::new(),
+            counts: self.counts.clone(),
+        };
+
+        for (pair, count) in &self.pairs {
+            let rule = &ruleset[pair];
+
+            // every occurence of the pair turns into two new pairs
+            for p in rule.produces {
+                add(&mut next.pairs, p, count)?;
+            }
+            add(&mut next.counts, rule.addition, count)?;
+        }
+
+        Some(next)
+    }
+
+    /// The length of the polymer, or `None` if it overflows.
+    pub fn len(&self) -> Option<C> {
+        self.counts
+            .values()
+            .try_fold(C::zero(), |sum, n| sum.checked_add(n))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The number of times an element occurs in the polymer.
+    pub fn count(&self, element: char) -> C {
+        self.counts.get(&element).cloned().unwrap_or_else(C::zero)
+    }
+
+    pub fn max(&self) -> C {
+        self.counts.values().max().unwrap().clone()
+    }
+
+    pub fn min(&self) -> C {
+        self.counts.values().min().unwrap().clone()
+    }
+}
+
+/// Performs pair insertion on the polymer as a string. The length doubles
+/// with every step, so this is only feasible for a few steps and serves as a
+/// reference for `Polymer`.
+pub fn expand_naive(template: &str, ruleset: &HashMap<Pair, Rule>, steps: usize) -> String {
+    let mut polymer = template.to_string();
+
+    for _ in 0..steps {
+        let chars: Vec<char> = polymer.chars().collect();
+        let mut next = String::new();
+
+        for w in chars.windows(2) {
+            next.push(w[0]);
+            next.push(ruleset[&Pair(w[0], w[1])].addition);
+        }
+        next.extend(chars.last());
+
+        polymer = next;
+    }
+
+    polymer
+}
+
+pub fn parse(s: &str) -> Result<(Polymer, HashMap<Pair, Rule>), ParseError> {
+    let mut lines = Scanner::lines(s);
+
+    let mut l = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(0, "the polymer template"))?;
+    let template = l.take_while(|c| c.is_ascii_uppercase());
+    if template.is_empty() {
+        return Err(l.error("an element"));
+    }
+    l.end()?;
+
+    let polymer = Polymer::new(template);
+
+    match lines.next() {
+        Some(l) => l.end()?,
+        None => return Err(ParseError::end_of_input(1, "an empty line")),
+    }
+
+    // the line of every rule, to tell which one needs a missing rule
+    let mut ruleset: HashMap<Pair, Rule> = HashMap::new();
+    let mut rule_lines = HashMap::new();
+    let mut count = 2;
+    for mut l in lines {
+        let r = Rule::parse(&mut l)?;
+        rule_lines.insert(r.pattern, l.lineno());
+        ruleset.insert(r.pattern, r);
+        count = l.lineno();
+    }
+
+    // every pair the polymer will contain needs a rule, so follow the rules
+    // from the pairs of the template, in order
+    let chars: Vec<char> = template.chars().collect();
+    let mut stack: Vec<(Pair, Option<usize>)> = chars
+        .windows(2)
+        .rev()
+        .map(|w| (Pair(w[0], w[1]), None))
+        .collect();
+    let mut seen = HashSet::new();
+
+    while let Some((pair, from)) = stack.pop() {
+        if !seen.insert(pair) {
+            continue;
+        }
+
+        let rule = ruleset.get(&pair).ok_or_else(|| {
+            let expected = match from {
+                None => format!("a rule for {:?}, which is in the template", pair),
+                Some(line) => format!(
+                    "a rule for {:?}, which the rule on line {} produces",
+                    pair, line
+                ),
+            };
+            ParseError::end_of_input(count, expected)
+        })?;
+
+        let line = rule_lines[&pair];
+        stack.extend(rule.produces.iter().rev().map(|&p| (p, Some(line))));
+    }
+
+    Ok((polymer, ruleset))
+}
+
+pub const STEPS1: Param = Param {
+    name: "steps1",
+    default: 10,
+    min: 0,
+    max: 10_000,
+    help: "insertion steps in part 1",
+};
+
+pub const STEPS2: Param = Param {
+    name: "steps2",
+    default: 40,
+    min: 0,
+    max: 10_000,
+    help: "insertion steps in part 2",
+};
+
+/// Returns the difference between the most and least common element after
+/// the given number of steps, counted with `C`.
+pub fn score<C: Counter>(
+    (polymer, ruleset): &(Polymer, HashMap<Pair, Rule>),
+    steps: usize,
+) -> Result<C, Overflow> {
+    let mut polymer = polymer.to_counter::<C>();
+
+    for step in 1..=steps {
+        polymer = polymer.step(ruleset).ok_or(Overflow {
+            counter: C::NAME,
+            step,
+        })?;
+    }
+
+    Ok(polymer
+        .max()
+        .checked_sub(&polymer.min())
+        .expect("the maximum is at least the minimum"))
+}
+
+pub fn part1(manual: &(Polymer, HashMap<Pair, Rule>)) -> Result<u64, Overflow> {
+    score(manual, STEPS1.default as usize)
+}
+
+pub fn part2(manual: &(Polymer, HashMap<Pair, Rule>)) -> Result<u64, Overflow> {
+    score(manual, STEPS2.default as usize)
+}
+
+/// Generates a template of `size` elements and a rule for every pair of ten
+/// elements.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elements: Vec<char> = "BCFHKNOPSV".chars().collect();
+
+    let mut s: String = (0..size.max(2)).map(|_| *rng.choose(&elements)).collect();
+    s += "\n\n";
+
+    for &a in &elements {
+        for &b in &elements {
+            s += &format!("{}{} -> {}\n", a, b, rng.choose(&elements));
+        }
+    }
+
+    s
+}
+
+/// The solver, counting the elements with `C`. Parts whose counts overflow
+/// have no answer.
+pub struct Day14<C = u64>(PhantomData<C>);
+
+impl<C: Counter> Solution for Day14<C> {
+    const DAY: u32 = 14;
+    type Input = (Polymer, HashMap<Pair, Rule>);
+    type Answer1 = Result<C, Overflow>;
+    type Answer2 = Result<C, Overflow>;
+
+    const PARAMS: &'static [Param] = &[STEPS1, STEPS2];
+
+    fn parse(s: &str) -> Result<(Polymer, HashMap<Pair, Rule>), ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &(Polymer, HashMap<Pair, Rule>), params: &Params) -> Result<C, Overflow> {
+        score(input, params.get(&STEPS1) as usize)
+    }
+
+    fn part2(input: &(Polymer, HashMap<Pair, Rule>), params: &Params) -> Result<C, Overflow> {
+        score(input, params.get(&STEPS2) as usize)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,117 @@
+//! Day 15: Chiton
+
+use crate::grid::{self, Grid};
+use crate::image::{self, Image};
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::search::{self, Found};
+use crate::solution::Solution;
+
+pub const TILES: Param = Param {
+    name: "tiles",
+    default: 5,
+    min: 1,
+    max: 50,
+    help: "times the map is repeated in each direction in part 2",
+};
+
+/// Returns the full map, which is the scanned tile repeated `n` times in each
+/// direction with increasing risk levels.
+pub fn enlarge(tile: &Grid<u8>, n: usize) -> Grid<u8> {
+    let (w, h) = (tile.width(), tile.height());
+    let mut board = Grid::filled(w * n, h * n, 0);
+
+    for (x, y) in board.positions() {
+        let inc = (x / w + y / h) % 9;
+        board[(x, y)] = ((tile[(x % w, y % h)] as usize + inc - 1) % 9 + 1) as u8;
+    }
+
+    board
+}
+
+/// Returns the path with the lowest total risk from the top left to the bottom
+/// right corner. The risk of the starting position isn't counted.
+pub fn safest_path(b: &Grid<u8>) -> Found<(usize, usize), u32> {
+    let target = (b.width() - 1, b.height() - 1);
+
+    search::dijkstra(
+        [(0, 0)],
+        |&p| b.neighbors4(p).map(|n| (n, u32::from(b[n]))),
+        |&p| p == target,
+    )
+    .expect("the bottom right corner is reachable")
+}
+
+/// Parses the risk levels, which are 1 to 9.
+pub fn parse(s: &str) -> Result<Grid<u8>, ParseError> {
+    grid::parse_cells(s, "a digit from 1 to 9", |c| match c.to_digit(10) {
+        Some(d @ 1..=9) => Some(d as u8),
+        _ => None,
+    })
+}
+
+pub fn part1(board: &Grid<u8>) -> u32 {
+    safest_path(board).cost
+}
+
+pub fn part2(board: &Grid<u8>) -> u32 {
+    safest_path(&enlarge(board, TILES.default as usize)).cost
+}
+
+/// Draws the risk levels in shades of gray, from dark for low risk to light
+/// for high risk, with the safest path in red.
+pub fn path_image(board: &Grid<u8>) -> Image {
+    let mut image = board.map(|&risk| image::gray(risk.into(), 12));
+
+    for &p in &safest_path(board).path {
+        image[p] = image::RED;
+    }
+
+    image
+}
+
+/// Generates a `size` by `size` map of risk levels.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            s += &rng.between(1, 9).to_string();
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const PARAMS: &'static [Param] = &[TILES];
+
+    fn parse(s: &str) -> Result<Grid<u8>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Grid<u8>, _params: &Params) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<u8>, params: &Params) -> u32 {
+        safest_path(&enlarge(input, params.get(&TILES) as usize)).cost
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn image(input: &Grid<u8>, params: &Params) -> Option<Image> {
+        Some(path_image(&enlarge(input, params.get(&TILES) as usize)))
+    }
+}
@@ -0,0 +1,405 @@
+//! Day 16: Packet Decoder
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::fmt::Write;
+
+pub fn hex_to_bits(s: &str) -> Option<String> {
+    let mut bits = String::new();
+
+    for c in s.chars() {
+        let b = match c {
+            '0' => "0000",
+            '1' => "0001",
+            '2' => "0010",
+            '3' => "0011",
+            '4' => "0100",
+            '5' => "0101",
+            '6' => "0110",
+            '7' => "0111",
+            '8' => "1000",
+            '9' => "1001",
+            'A' => "1010",
+            'B' => "1011",
+            'C' => "1100",
+            'D' => "1101",
+            'E' => "1110",
+            'F' => "1111",
+            _ => {
+                return None;
+            }
+        };
+
+        write!(bits, "{}", b).unwrap();
+    }
+
+    Some(bits)
+}
+
+#[derive(Debug)]
+pub enum PacketContent {
+    Literal(u64),
+    Operator(Vec<Packet>),
+}
+
+#[derive(Debug)]
+pub struct Packet {
+    pub version: u8,
+    pub typeid: u8,
+    pub content: PacketContent,
+}
+
+fn parse_fint(s: &str, size: usize) -> Option<(&str, u16)> {
+    assert!(size == 15 || size == 11);
+
+    let (num, rest) = s.split_at_checked(size)?;
+
+    let mut ret = 0;
+    for c in num.chars() {
+        ret <<= 1;
+        if c == '1' {
+            ret |= 1;
+        }
+    }
+
+    Some((rest, ret))
+}
+
+fn parse_three(s: &str) -> Option<(&str, u8)> {
+    let (three, rest) = s.split_at_checked(3)?;
+    assert_eq!(three.len(), 3);
+
+    let mut ret = 0;
+    if three.chars().next()? == '1' {
+        ret += 4;
+    }
+    if three.chars().nth(1)? == '1' {
+        ret += 2;
+    }
+    if three.chars().nth(2)? == '1' {
+        ret += 1;
+    }
+
+    Some((rest, ret))
+}
+
+fn parse_four(s: &str) -> Option<(&str, u8)> {
+    let (three, rest) = s.split_at_checked(4)?;
+    assert_eq!(three.len(), 4);
+
+    let mut ret = 0;
+    if three.chars().next()? == '1' {
+        ret += 8;
+    }
+    if three.chars().nth(1)? == '1' {
+        ret += 4;
+    }
+    if three.chars().nth(2)? == '1' {
+        ret += 2;
+    }
+    if three.chars().nth(3)? == '1' {
+        ret += 1;
+    }
+
+    Some((rest, ret))
+}
+
+fn parse_one(s: &str) -> Option<(&str, bool)> {
+    let (one, rest) = s.split_at_checked(1)?;
+    assert_eq!(one.len(), 1);
+
+    Some((rest, one.chars().next()? == '1'))
+}
+
+fn parse_literal(s: &str) -> Option<(&str, PacketContent)> {
+    let mut ss = s;
+    let mut ret: u64 = 0;
+
+    loop {
+        let (rest, notlast) = parse_one(ss)?;
+        let (rest, val) = parse_four(rest)?;
+
+        ret = (ret << 4) | val as u64;
+
+        ss = rest;
+        if !notlast {
+            break;
+        }
+    }
+
+    Some((ss, PacketContent::Literal(ret)))
+}
+
+fn parse_operator(s: &str) -> Option<(&str, PacketContent)> {
+    let (rest, not_bit_len) = parse_one(s)?;
+    let is_bit_len = !not_bit_len;
+    let (rest, len) = if is_bit_len {
+        parse_fint(rest, 15)?
+    } else {
+        parse_fint(rest, 11)?
+    };
+    let mut ss = rest;
+
+    let mut subpackets = Vec::new();
+
+    if is_bit_len {
+        while rest.len() - ss.len() < len.into() {
+            let (rrest, p) = parse_packet(ss)?;
+            subpackets.push(p);
+            ss = rrest;
+        }
+    } else {
+        for _ in 0..len {
+            let (rest, p) = parse_packet(ss)?;
+            subpackets.push(p);
+            ss = rest;
+        }
+    }
+
+    Some((ss, PacketContent::Operator(subpackets)))
+}
+
+pub fn parse_packet(s: &str) -> Option<(&str, Packet)> {
+    let (rest, version) = parse_three(s)?;
+    let (rest, typeid) = parse_three(rest)?;
+
+    if typeid == 4 {
+        let (rest, content) = parse_literal(rest)?;
+        Some((
+            rest,
+            Packet {
+                version,
+                typeid,
+                content,
+            },
+        ))
+    } else {
+        let (rest, content) = parse_operator(rest)?;
+        Some((
+            rest,
+            Packet {
+                version,
+                typeid,
+                content,
+            },
+        ))
+    }
+}
+
+pub fn version_sum(p: &Packet) -> u32 {
+    let mut s = p.version as u32;
+
+    if let PacketContent::Operator(subpackets) = &p.content {
+        for sp in subpackets {
+            s += version_sum(sp);
+        }
+    }
+
+    s
+}
+
+pub fn eval_packet(p: &Packet) -> Option<u64> {
+    match &p.content {
+        PacketContent::Operator(sps) => match p.typeid {
+            0 => Some(sps.iter().flat_map(eval_packet).sum()),
+            1 => Some(sps.iter().flat_map(eval_packet).product()),
+            2 => Some(sps.iter().flat_map(eval_packet).min()?),
+            3 => Some(sps.iter().flat_map(eval_packet).max()?),
+            5 => {
+                let left = eval_packet(sps.first()?)?;
+                let right = eval_packet(sps.get(1)?)?;
+
+                Some(if left > right { 1 } else { 0 })
+            }
+            6 => {
+                let left = eval_packet(sps.first()?)?;
+                let right = eval_packet(sps.get(1)?)?;
+
+                Some(if left < right { 1 } else { 0 })
+            }
+            7 => {
+                let left = eval_packet(sps.first()?)?;
+                let right = eval_packet(sps.get(1)?)?;
+
+                Some(if left == right { 1 } else { 0 })
+            }
+            _ => None,
+        },
+        PacketContent::Literal(n) => Some(*n),
+    }
+}
+
+/// Checks that every operator packet has the subpackets it needs: exactly two
+/// for the comparisons and at least one for the others. Returns what was
+/// expected and what was found otherwise.
+fn check_subpackets(p: &Packet) -> Result<(), (String, String)> {
+    let PacketContent::Operator(sps) = &p.content else {
+        return Ok(());
+    };
+
+    let found = format!("{} subpackets", sps.len());
+    match p.typeid {
+        5..=7 if sps.len() != 2 => Err((
+            format!("2 subpackets in a packet of type {}", p.typeid),
+            found,
+        )),
+        _ if sps.is_empty() => Err((
+            format!("subpackets in a packet of type {}", p.typeid),
+            found,
+        )),
+        _ => sps.iter().try_for_each(check_subpackets),
+    }
+}
+
+/// Parses one transmission per line.
+pub fn parse(s: &str) -> Result<Vec<Packet>, ParseError> {
+    Scanner::lines(s)
+        .map(|mut l| {
+            let hex = l.take_while(|c| c.is_ascii_digit() || ('A'..='F').contains(&c));
+            if hex.is_empty() || !l.is_done() {
+                return Err(l.error("a hexadecimal digit"));
+            }
+
+            let bits = hex_to_bits(hex).unwrap();
+            let packet = match parse_packet(&bits) {
+                Some((_, packet)) => packet,
+                None => return Err(l.error("the rest of the packet")),
+            };
+
+            check_subpackets(&packet)
+                .map_err(|(expected, found)| ParseError::new(l.lineno(), 1, expected, found))?;
+
+            Ok(packet)
+        })
+        .collect()
+}
+
+pub fn part1(packet: &Packet) -> u32 {
+    version_sum(packet)
+}
+
+pub fn part2(packet: &Packet) -> u64 {
+    eval_packet(packet).expect("parse checks the subpackets")
+}
+
+fn push_bits(bits: &mut String, value: u64, width: usize) {
+    for i in (0..width).rev() {
+        bits.push(if (value >> i) & 1 == 1 { '1' } else { '0' });
+    }
+}
+
+fn generate_literal(rng: &mut Rng, bits: &mut String, max: u64) {
+    push_bits(bits, rng.below(8) as u64, 3);
+    push_bits(bits, 4, 3);
+
+    let value = rng.below(max as usize) as u64;
+    let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+
+    for i in (0..groups).rev() {
+        push_bits(bits, u64::from(i > 0), 1);
+        push_bits(bits, (value >> (i * 4)) & 0xf, 4);
+    }
+}
+
+/// Writes a packet with about `budget` packets in it. Products only contain
+/// a few small literals so that no value overflows.
+fn generate_packet(rng: &mut Rng, bits: &mut String, budget: &mut usize, depth: usize) {
+    *budget = budget.saturating_sub(1);
+
+    if depth == 0 || *budget == 0 || rng.chance(0.2) {
+        generate_literal(rng, bits, 1 << 20);
+        return;
+    }
+
+    let typeid = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let mut children = String::new();
+
+    let count = match typeid {
+        1 => {
+            let count = rng.between(1, 4) as usize;
+            for _ in 0..count {
+                generate_literal(rng, &mut children, 16);
+            }
+            count
+        }
+        5..=7 => {
+            for _ in 0..2 {
+                generate_packet(rng, &mut children, budget, depth - 1);
+            }
+            2
+        }
+        _ => {
+            let count = rng.between(1, 4) as usize;
+            for _ in 0..count {
+                generate_packet(rng, &mut children, budget, depth - 1);
+            }
+            count
+        }
+    };
+
+    push_bits(bits, rng.below(8) as u64, 3);
+    push_bits(bits, typeid, 3);
+
+    if children.len() < 1 << 15 && rng.chance(0.5) {
+        push_bits(bits, 0, 1);
+        push_bits(bits, children.len() as u64, 15);
+    } else {
+        push_bits(bits, 1, 1);
+        push_bits(bits, count as u64, 11);
+    }
+
+    bits.push_str(&children);
+}
+
+/// Generates a transmission of a single packet with about `size` packets
+/// nested in it, up to 20 levels deep.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut bits = String::new();
+    generate_packet(rng, &mut bits, &mut size.max(1), 20);
+
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+
+    let mut s: String = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let n = nibble.iter().fold(0, |n, &b| n << 1 | u32::from(b == b'1'));
+            char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+
+    s.push('\n');
+    s
+}
+
+/// Every line is a packet. The answers of all packets are joined by commas.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = Vec<Packet>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(s: &str) -> Result<Vec<Packet>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<Packet>, _params: &Params) -> String {
+        let answers: Vec<String> = input.iter().map(|p| part1(p).to_string()).collect();
+        answers.join(",")
+    }
+
+    fn part2(input: &Vec<Packet>, _params: &Params) -> String {
+        let answers: Vec<String> = input.iter().map(|p| part2(p).to_string()).collect();
+        answers.join(",")
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,313 @@
+//! Day 17: Trick Shot
+
+use crate::animate::{self, Frames};
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+#[derive(Clone, Copy, Debug)]
+pub struct TargetArea {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// Reads `min..max` with `low <= min <= max <= high`.
+fn range(l: &mut Scanner, low: i32, high: i32) -> Result<(i32, i32), ParseError> {
+    let bounded = |l: &mut Scanner, low: i32| {
+        let at = l.clone();
+        let n = l.number::<i32>()?;
+
+        if n < low {
+            Err(at.error(format!("a number of at least {}", low)))
+        } else if n > high {
+            Err(at.error(format!("a number of at most {}", high)))
+        } else {
+            Ok(n)
+        }
+    };
+
+    let min = bounded(l, low)?;
+    l.tag("..")?;
+    let max = bounded(l, min)?;
+
+    Ok((min, max))
+}
+
+impl TargetArea {
+    /// Parses the target area, which has to be to the right of the launcher
+    /// and below it.
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let mut l = Scanner::new(s, 1);
+
+        l.tag("target area: x=")?;
+        let (x1, x2) = range(&mut l, 1, i32::MAX)?;
+        l.tag(", y=")?;
+        let (y1, y2) = range(&mut l, i32::MIN, -1)?;
+        l.end()?;
+
+        Ok(TargetArea {
+            x: x1,
+            y: y1,
+            width: x2 - x1,
+            height: y2 - y1,
+        })
+    }
+
+    pub fn shoot(&self, vel: (i32, i32)) -> Shot {
+        Shot::new(*self, vel)
+    }
+
+    pub fn inside(&self, p: (i32, i32)) -> bool {
+        p.0 >= self.x && p.0 <= self.x + self.width && p.1 >= self.y && p.1 <= self.y + self.height
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Shot {
+    target: TargetArea,
+    position: (i32, i32),
+    velocity: (i32, i32),
+    target_hit: bool,
+}
+
+impl Shot {
+    pub fn new(target: TargetArea, velocity: (i32, i32)) -> Self {
+        Self {
+            target,
+            velocity,
+            position: (0, 0),
+            target_hit: false,
+        }
+    }
+}
+
+impl Iterator for Shot {
+    // x, y, target was hit
+    type Item = (i32, i32, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position.0 > self.target.x + self.target.width || self.position.1 < self.target.y {
+            // probe is to the right or lower than the target and can never hit
+            // it
+
+            None
+        } else {
+            let ret = Some((self.position.0, self.position.1, self.target_hit));
+
+            self.position.0 += self.velocity.0;
+            self.position.1 += self.velocity.1;
+
+            if self.velocity.0 > 0 {
+                self.velocity.0 -= 1;
+            } else if self.velocity.0 < 0 {
+                self.velocity.0 += 1;
+            }
+
+            self.velocity.1 -= 1;
+
+            if self.target.inside(self.position) {
+                self.target_hit = true;
+            }
+
+            ret
+        }
+    }
+}
+
+/// Returns the highest point of the trajectory if it hits the target.
+pub fn high_point(s: Shot) -> Option<i32> {
+    let states: Vec<(i32, i32, bool)> = s.collect();
+
+    if !states.iter().any(|x| x.2) {
+        None
+    } else {
+        states.into_iter().map(|x| x.1).max()
+    }
+}
+
+pub fn parse(s: &str) -> Result<TargetArea, ParseError> {
+    TargetArea::new(s.lines().next().unwrap_or(""))
+}
+
+pub const MAX_VELOCITY: Param = Param {
+    name: "max-velocity",
+    default: 500,
+    min: 0,
+    max: 10_000,
+    help: "upper bound of the vertical launch velocities tried",
+};
+
+/// Returns the launch velocity that hits the target with the highest
+/// trajectory and its highest point, trying vertical velocities below
+/// `max_velocity`.
+pub fn best_shot(target: &TargetArea, max_velocity: i32) -> Option<((i32, i32), i32)> {
+    let mut best = None;
+    let mut highest = 0;
+
+    for x in 0..target.x {
+        for y in 0..max_velocity {
+            if let Some(h) = high_point(target.shoot((x, y))) {
+                if best.is_none() || h > highest {
+                    best = Some((x, y));
+                    highest = h;
+                }
+            }
+        }
+    }
+
+    best.map(|v| (v, highest))
+}
+
+/// Returns the highest point reached by any launch that hits the target,
+/// trying vertical velocities below `max_velocity`.
+pub fn highest(target: &TargetArea, max_velocity: i32) -> i32 {
+    best_shot(target, max_velocity).map_or(0, |(_, h)| h.max(0))
+}
+
+/// Returns the number of launch velocities that hit the target, trying
+/// vertical velocities below `max_velocity`.
+pub fn hits(target: &TargetArea, max_velocity: i32) -> usize {
+    let mut hits = Vec::new();
+
+    for x in 0..=target.x + target.width {
+        for y in target.y..max_velocity {
+            if high_point(target.shoot((x, y))).is_some() {
+                hits.push((x, y));
+            }
+        }
+    }
+
+    hits.len()
+}
+
+pub fn part1(target: &TargetArea) -> i32 {
+    highest(target, MAX_VELOCITY.default as i32)
+}
+
+pub fn part2(target: &TargetArea) -> usize {
+    hits(target, MAX_VELOCITY.default as i32)
+}
+
+/// Size of the drawing in `render`. Trajectories are scaled down to fit.
+const COLUMNS: i64 = 72;
+const ROWS: i64 = 30;
+
+/// Draws the target area and the trajectory so far, with the probe
+/// highlighted. `bounds` are the lowest and highest coordinates to draw.
+pub fn render(
+    target: &TargetArea,
+    trajectory: &[(i32, i32)],
+    (min, max): ((i32, i32), (i32, i32)),
+) -> String {
+    let column = |x: i32| (x - min.0) as i64 * (COLUMNS - 1) / (max.0 - min.0).max(1) as i64;
+    let row = |y: i32| (max.1 - y) as i64 * (ROWS - 1) / (max.1 - min.1).max(1) as i64;
+
+    let mut canvas = vec![vec!['.'; COLUMNS as usize]; ROWS as usize];
+
+    for r in row(target.y + target.height)..=row(target.y) {
+        for c in column(target.x)..=column(target.x + target.width) {
+            canvas[r as usize][c as usize] = 'T';
+        }
+    }
+
+    for &(x, y) in trajectory {
+        canvas[row(y) as usize][column(x) as usize] = '#';
+    }
+    canvas[row(0) as usize][column(0) as usize] = 'S';
+
+    let probe = trajectory.last().map(|&(x, y)| (row(y), column(x)));
+    let mut s = String::new();
+
+    for (r, line) in canvas.iter().enumerate() {
+        for (c, &ch) in line.iter().enumerate() {
+            match ch {
+                _ if probe == Some((r as i64, c as i64)) => s += &animate::highlight("@"),
+                '.' => s += &animate::dim("."),
+                ch => s.push(ch),
+            }
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+/// The flight of the probe on the highest trajectory that hits the target.
+pub fn frames(target: &TargetArea, max_velocity: i32) -> Option<Frames<'static>> {
+    let target = *target;
+    let (velocity, _) = best_shot(&target, max_velocity)?;
+
+    let trajectory: Vec<(i32, i32)> = target.shoot(velocity).map(|(x, y, _)| (x, y)).collect();
+    let corners = [
+        (0, 0),
+        (target.x, target.y),
+        (target.x + target.width, target.y + target.height),
+    ];
+    let points = trajectory.iter().chain(&corners);
+    let min = points
+        .clone()
+        .fold((i32::MAX, i32::MAX), |m, p| (m.0.min(p.0), m.1.min(p.1)));
+    let max = points.fold((i32::MIN, i32::MIN), |m, p| (m.0.max(p.0), m.1.max(p.1)));
+
+    Some(Box::new((1..=trajectory.len()).map(move |n| {
+        let (x, y) = trajectory[n - 1];
+        format!(
+            "velocity {},{}  step {}  position {},{}\n{}",
+            velocity.0,
+            velocity.1,
+            n - 1,
+            x,
+            y,
+            render(&target, &trajectory[..n], (min, max))
+        )
+    })))
+}
+
+/// Generates a target area up to `size` to the right of the launcher and up
+/// to `size` below it. The depth is capped at 400 to stay within the
+/// default `MAX_VELOCITY`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2) as i64;
+    let depth = size.min(400);
+
+    let x1 = rng.between(size / 2, size);
+    let x2 = x1 + rng.between(0, size / 4);
+    let y1 = -rng.between(depth / 2, depth);
+    let y2 = y1 + rng.between(0, depth / 4);
+
+    format!("target area: x={}..{}, y={}..{}\n", x1, x2, y1, y2)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = TargetArea;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    const PARAMS: &'static [Param] = &[MAX_VELOCITY];
+
+    fn parse(s: &str) -> Result<TargetArea, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &TargetArea, params: &Params) -> i32 {
+        highest(input, params.get(&MAX_VELOCITY) as i32)
+    }
+
+    fn part2(input: &TargetArea, params: &Params) -> usize {
+        hits(input, params.get(&MAX_VELOCITY) as i32)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn frames<'a>(input: &'a TargetArea, params: &Params) -> Option<Frames<'a>> {
+        frames(input, params.get(&MAX_VELOCITY) as i32)
+    }
+}
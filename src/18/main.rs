@@ -0,0 +1,5 @@
+use aoc2021::{day18::Day18, solution};
+
+fn main() {
+    solution::main::<Day18>();
+}
@@ -0,0 +1,245 @@
+//! Day 18: Snailfish
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::fmt;
+use std::ops::Add;
+
+/// A snailfish number, which is a pair of elements that are regular numbers
+/// or pairs themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnailNum {
+    Regular(u32),
+    Pair(Box<SnailNum>, Box<SnailNum>),
+}
+
+use SnailNum::{Pair, Regular};
+
+/// Pairs nested inside this many pairs explode.
+const MAX_DEPTH: usize = 4;
+
+/// Regular numbers from this value on split.
+const SPLIT: u32 = 10;
+
+impl SnailNum {
+    pub fn pair(left: SnailNum, right: SnailNum) -> Self {
+        Pair(Box::new(left), Box::new(right))
+    }
+
+    /// Reads an element, which is a regular number or a pair.
+    fn element(l: &mut Scanner) -> Result<Self, ParseError> {
+        if l.peek() != Some('[') {
+            return Ok(Regular(l.number()?));
+        }
+
+        l.tag("[")?;
+        let left = Self::element(l)?;
+        l.tag(",")?;
+        let right = Self::element(l)?;
+        l.tag("]")?;
+
+        Ok(Self::pair(left, right))
+    }
+
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        if l.peek() != Some('[') {
+            return Err(l.error("'['"));
+        }
+
+        let n = Self::element(l)?;
+        l.end()?;
+
+        Ok(n)
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        match self {
+            Regular(n) => *n as u64,
+            Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+
+    /// Adds `n` to the leftmost regular number.
+    fn add_leftmost(&mut self, n: u32) {
+        match self {
+            Regular(v) => *v += n,
+            Pair(left, _) => left.add_leftmost(n),
+        }
+    }
+
+    /// Adds `n` to the rightmost regular number.
+    fn add_rightmost(&mut self, n: u32) {
+        match self {
+            Regular(v) => *v += n,
+            Pair(_, right) => right.add_rightmost(n),
+        }
+    }
+
+    /// Explodes the leftmost pair of regular numbers that is nested at
+    /// least `depth` levels deep. Returns the numbers that still have to be
+    /// added to the regular numbers left and right of it.
+    fn explode_at(&mut self, depth: usize) -> Option<(u32, u32)> {
+        let Pair(left, right) = self else {
+            return None;
+        };
+
+        if depth >= MAX_DEPTH {
+            if let (Regular(a), Regular(b)) = (left.as_ref(), right.as_ref()) {
+                let carry = (*a, *b);
+                *self = Regular(0);
+                return Some(carry);
+            }
+        }
+
+        if let Some((a, b)) = left.explode_at(depth + 1) {
+            right.add_leftmost(b);
+            return Some((a, 0));
+        }
+        if let Some((a, b)) = right.explode_at(depth + 1) {
+            left.add_rightmost(a);
+            return Some((0, b));
+        }
+
+        None
+    }
+
+    /// Explodes the leftmost pair nested inside four pairs. Returns whether
+    /// there was one.
+    pub fn explode(&mut self) -> bool {
+        self.explode_at(0).is_some()
+    }
+
+    /// Splits the leftmost regular number of 10 or more into a pair of its
+    /// halves. Returns whether there was one.
+    pub fn split(&mut self) -> bool {
+        match self {
+            Regular(n) if *n >= SPLIT => {
+                *self = Self::pair(Regular(*n / 2), Regular(n.div_ceil(2)));
+                true
+            }
+            Regular(_) => false,
+            Pair(left, right) => left.split() || right.split(),
+        }
+    }
+
+    /// Explodes and splits until neither is possible, always exploding
+    /// first.
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+}
+
+impl Add for SnailNum {
+    type Output = SnailNum;
+
+    /// Pairs the numbers and reduces the pair.
+    fn add(self, other: SnailNum) -> SnailNum {
+        let mut sum = Self::pair(self, other);
+        sum.reduce();
+        sum
+    }
+}
+
+impl fmt::Display for SnailNum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Regular(n) => write!(f, "{}", n),
+            Pair(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+impl std::str::FromStr for SnailNum {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        SnailNum::parse(&mut Scanner::new(s, 1))
+    }
+}
+
+pub fn parse(s: &str) -> Result<Vec<SnailNum>, ParseError> {
+    let numbers = Scanner::lines(s)
+        .map(|mut l| SnailNum::parse(&mut l))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if numbers.is_empty() {
+        return Err(ParseError::end_of_input(0, "a snailfish number"));
+    }
+
+    Ok(numbers)
+}
+
+/// Adds up the numbers from first to last.
+pub fn sum(numbers: &[SnailNum]) -> SnailNum {
+    numbers
+        .iter()
+        .cloned()
+        .reduce(Add::add)
+        .expect("at least one number")
+}
+
+pub fn part1(numbers: &[SnailNum]) -> u64 {
+    sum(numbers).magnitude()
+}
+
+/// The largest magnitude of the sum of two different numbers, which depends
+/// on their order.
+pub fn part2(numbers: &[SnailNum]) -> u64 {
+    let mut max = 0;
+
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                max = max.max((a.clone() + b.clone()).magnitude());
+            }
+        }
+    }
+
+    max
+}
+
+/// A reduced element nested inside `depth` pairs.
+fn generate_element(rng: &mut Rng, depth: usize) -> SnailNum {
+    if depth == MAX_DEPTH || (depth > 0 && rng.chance(0.4)) {
+        return Regular(rng.below(SPLIT as usize) as u32);
+    }
+
+    SnailNum::pair(
+        generate_element(rng, depth + 1),
+        generate_element(rng, depth + 1),
+    )
+}
+
+/// Generates `size` reduced snailfish numbers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(2))
+        .map(|_| format!("{}\n", generate_element(rng, 0)))
+        .collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = Vec<SnailNum>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(s: &str) -> Result<Vec<SnailNum>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<SnailNum>, _params: &Params) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<SnailNum>, _params: &Params) -> u64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,5 @@
+use aoc2021::{day19::Day19, solution};
+
+fn main() {
+    solution::main::<Day19>();
+}
@@ -0,0 +1,369 @@
+//! Day 19: Beacon Scanner
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use crate::space::{Point3, Rotation};
+use std::cell::OnceCell;
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt;
+
+/// Scanners detect the beacons up to this far away along every axis.
+pub const RANGE: i64 = 1000;
+
+/// The number of beacons two scanners need to detect both to be aligned.
+pub const OVERLAP: usize = 12;
+
+/// The beacons that one scanner detects, relative to its position and in
+/// its orientation.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub beacons: Vec<Point3>,
+    /// The squared distances between all pairs of beacons, sorted. They
+    /// don't depend on the position and orientation of the scanner.
+    fingerprint: Vec<i64>,
+}
+
+impl Report {
+    pub fn new(beacons: Vec<Point3>) -> Self {
+        let mut fingerprint = Vec::new();
+        for (i, &a) in beacons.iter().enumerate() {
+            for &b in &beacons[i + 1..] {
+                fingerprint.push((a - b).length_squared());
+            }
+        }
+        fingerprint.sort_unstable();
+
+        Self {
+            beacons,
+            fingerprint,
+        }
+    }
+
+    /// The number of distances between beacons that both reports have. Two
+    /// scanners that detect the same 12 beacons have at least the 66
+    /// distances between them in common.
+    fn common_distances(&self, other: &Report) -> usize {
+        let (a, b) = (&self.fingerprint, &other.fingerprint);
+        let (mut i, mut j, mut common) = (0, 0, 0);
+
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    common += 1;
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        common
+    }
+}
+
+/// Where a scanner is and how it is turned, relative to scanner 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Placement {
+    pub position: Point3,
+    /// Turns the report of the scanner into the orientation of scanner 0.
+    pub rotation: Rotation,
+}
+
+impl Placement {
+    /// The position of a beacon in the report of the scanner relative to
+    /// scanner 0.
+    pub fn locate(&self, beacon: Point3) -> Point3 {
+        self.position + self.rotation.apply(beacon)
+    }
+}
+
+/// Places a scanner so that at least 12 of its beacons are at the known
+/// positions of beacons.
+fn place(known: &[Point3], report: &Report, rotations: &[Rotation]) -> Option<Placement> {
+    for &rotation in rotations {
+        // every pair of a known and a detected beacon votes for the position
+        // of the scanner that puts them on top of each other
+        let mut votes: HashMap<Point3, usize> = HashMap::new();
+
+        for &b in &report.beacons {
+            let b = rotation.apply(b);
+
+            for &a in known {
+                let n = votes.entry(a - b).or_insert(0);
+                *n += 1;
+
+                if *n == OVERLAP {
+                    return Some(Placement {
+                        position: a - b,
+                        rotation,
+                    });
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// All beacons and scanners relative to scanner 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Map {
+    pub beacons: BTreeSet<Point3>,
+    pub scanners: Vec<Placement>,
+}
+
+/// The scanners that share 12 beacons with none of the placed scanners.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Unaligned {
+    pub scanners: Vec<usize>,
+}
+
+impl fmt::Display for Unaligned {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scanners: Vec<String> = self.scanners.iter().map(|s| s.to_string()).collect();
+
+        write!(
+            f,
+            "scanner {} shares {} beacons with no placed scanner",
+            scanners.join(", "),
+            OVERLAP
+        )
+    }
+}
+
+impl Error for Unaligned {}
+
+/// Places all scanners, starting from scanner 0. Only scanners whose
+/// fingerprints have enough distances in common are compared beacon by
+/// beacon.
+pub fn align(reports: &[Report]) -> Result<Map, Unaligned> {
+    let rotations = Rotation::all();
+    let pairs = OVERLAP * (OVERLAP - 1) / 2;
+
+    let mut placements: Vec<Option<Placement>> = vec![None; reports.len()];
+    let mut located: Vec<Vec<Point3>> = vec![Vec::new(); reports.len()];
+    let mut queue = Vec::new();
+
+    if !reports.is_empty() {
+        placements[0] = Some(Placement {
+            position: Point3::ORIGIN,
+            rotation: Rotation::IDENTITY,
+        });
+        located[0] = reports[0].beacons.clone();
+        queue.push(0);
+    }
+
+    while let Some(i) = queue.pop() {
+        for (j, report) in reports.iter().enumerate() {
+            if placements[j].is_some() || reports[i].common_distances(report) < pairs {
+                continue;
+            }
+
+            if let Some(p) = place(&located[i], report, &rotations) {
+                located[j] = report.beacons.iter().map(|&b| p.locate(b)).collect();
+                placements[j] = Some(p);
+                queue.push(j);
+            }
+        }
+    }
+
+    let unaligned: Vec<usize> = (0..reports.len())
+        .filter(|&i| placements[i].is_none())
+        .collect();
+    if !unaligned.is_empty() {
+        return Err(Unaligned {
+            scanners: unaligned,
+        });
+    }
+
+    Ok(Map {
+        beacons: located.into_iter().flatten().collect(),
+        scanners: placements.into_iter().flatten().collect(),
+    })
+}
+
+fn beacon(l: &mut Scanner) -> Result<Point3, ParseError> {
+    let x = l.number()?;
+    l.tag(",")?;
+    let y = l.number()?;
+    l.tag(",")?;
+    let z = l.number()?;
+    l.end()?;
+
+    Ok(Point3::new(x, y, z))
+}
+
+/// The reports of the scanners and, once a part needs it, the map they
+/// align to.
+pub struct Scans {
+    pub reports: Vec<Report>,
+    map: OnceCell<Result<Map, Unaligned>>,
+}
+
+impl Scans {
+    pub fn new(reports: Vec<Report>) -> Self {
+        Self {
+            reports,
+            map: OnceCell::new(),
+        }
+    }
+
+    /// Aligns the scanners the first time it's called, so that both parts
+    /// share the work and the part solved first takes the time.
+    pub fn map(&self) -> Result<&Map, Unaligned> {
+        self.map
+            .get_or_init(|| align(&self.reports))
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+}
+
+/// Parses the reports of the scanners, which are numbered from 0.
+pub fn parse(s: &str) -> Result<Vec<Report>, ParseError> {
+    let mut lines = Scanner::lines(s);
+    let mut reports = Vec::new();
+
+    while let Some(mut l) = lines.next() {
+        l.tag("--- scanner ")?;
+        let number = l.clone();
+        if l.number::<usize>()? != reports.len() {
+            return Err(number.error(format!("scanner {}", reports.len())));
+        }
+        l.tag(" ---")?;
+        l.end()?;
+
+        let mut beacons = Vec::new();
+        for mut l in lines.by_ref() {
+            if l.is_done() {
+                break;
+            }
+            beacons.push(beacon(&mut l)?);
+        }
+
+        reports.push(Report::new(beacons));
+    }
+
+    if reports.is_empty() {
+        return Err(ParseError::end_of_input(0, "a scanner"));
+    }
+
+    Ok(reports)
+}
+
+pub fn part1(map: &Map) -> usize {
+    map.beacons.len()
+}
+
+/// The largest Manhattan distance between two scanners.
+pub fn part2(map: &Map) -> i64 {
+    map.scanners
+        .iter()
+        .flat_map(|a| {
+            map.scanners
+                .iter()
+                .map(|b| (a.position - b.position).manhattan())
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// A random point in the box between two corners.
+fn random_point(rng: &mut Rng, min: Point3, max: Point3) -> Point3 {
+    Point3::new(
+        rng.between(min.x, max.x),
+        rng.between(min.y, max.y),
+        rng.between(min.z, max.z),
+    )
+}
+
+/// Generates the reports of `size` scanners, up to 40. Every scanner is up
+/// to 1200 away along every axis from one before it, with 12 new beacons in
+/// the range of both and 10 more in its own range.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let range = Point3::new(RANGE, RANGE, RANGE);
+    let rotations = Rotation::all();
+
+    let mut scanners = vec![Point3::ORIGIN];
+    let mut beacons = BTreeSet::new();
+    while beacons.len() < OVERLAP + 10 {
+        beacons.insert(random_point(rng, -range, range));
+    }
+
+    for i in 1..size.clamp(2, 40) {
+        let near = scanners[rng.below(i)];
+        let far = Point3::new(1200, 1200, 1200);
+        let scanner = near + random_point(rng, -far, far);
+
+        // the box both scanners see
+        let min = near.zip_with(scanner, i64::max) - range;
+        let max = near.zip_with(scanner, i64::min) + range;
+
+        let mut added = 0;
+        while added < OVERLAP {
+            added += usize::from(beacons.insert(random_point(rng, min, max)));
+        }
+        while added < OVERLAP + 10 {
+            added +=
+                usize::from(beacons.insert(random_point(rng, scanner - range, scanner + range)));
+        }
+
+        scanners.push(scanner);
+    }
+
+    let mut s = String::new();
+    for (i, &scanner) in scanners.iter().enumerate() {
+        let rotation = if i == 0 {
+            Rotation::IDENTITY
+        } else {
+            *rng.choose(&rotations)
+        };
+
+        let mut detected: Vec<Point3> = beacons
+            .iter()
+            .map(|&b| b - scanner)
+            .filter(|b| b.coords().iter().all(|c| c.abs() <= RANGE))
+            .map(|b| rotation.apply(b))
+            .collect();
+        rng.shuffle(&mut detected);
+
+        if i > 0 {
+            s.push('\n');
+        }
+        s += &format!("--- scanner {} ---\n", i);
+        for b in detected {
+            s += &format!("{}\n", b);
+        }
+    }
+
+    s
+}
+
+/// The solver. Both parts have no answer if a scanner can't be placed.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = Scans;
+    type Answer1 = Result<usize, Unaligned>;
+    type Answer2 = Result<i64, Unaligned>;
+
+    fn parse(s: &str) -> Result<Scans, ParseError> {
+        Ok(Scans::new(parse(s)?))
+    }
+
+    fn part1(input: &Scans, _params: &Params) -> Result<usize, Unaligned> {
+        input.map().map(part1)
+    }
+
+    fn part2(input: &Scans, _params: &Params) -> Result<i64, Unaligned> {
+        input.map().map(part2)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
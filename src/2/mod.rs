@@ -0,0 +1,128 @@
+//! Day 2: Dive!
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Up(i64),
+    Down(i64),
+    Forward(i64),
+}
+
+impl Command {
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        let command = if l.tag("up").is_ok() {
+            Command::Up
+        } else if l.tag("down").is_ok() {
+            Command::Down
+        } else if l.tag("forward").is_ok() {
+            Command::Forward
+        } else {
+            return Err(l.error("'up', 'down' or 'forward'"));
+        };
+
+        l.tag(" ")?;
+        let length = l.number::<i64>()?;
+        l.end()?;
+
+        Ok(command(length))
+    }
+}
+
+impl std::str::FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Command::parse(&mut Scanner::new(s, 1))
+    }
+}
+
+pub fn parse(s: &str) -> Result<Vec<Command>, ParseError> {
+    Scanner::lines(s)
+        .map(|mut l| Command::parse(&mut l))
+        .collect()
+}
+
+pub fn part1(commands: &[Command]) -> i64 {
+    let mut depth = 0;
+    let mut forward = 0;
+
+    for c in commands {
+        match c {
+            Command::Up(length) => {
+                depth -= length;
+            }
+            Command::Down(length) => {
+                depth += length;
+            }
+            Command::Forward(length) => {
+                forward += length;
+            }
+        }
+    }
+
+    depth * forward
+}
+
+pub fn part2(commands: &[Command]) -> i64 {
+    let mut depth = 0;
+    let mut forward = 0;
+    let mut aim = 0;
+
+    for c in commands {
+        match c {
+            Command::Up(length) => {
+                aim -= length;
+            }
+            Command::Down(length) => {
+                aim += length;
+            }
+            Command::Forward(length) => {
+                forward += length;
+                depth += aim * length;
+            }
+        }
+    }
+
+    depth * forward
+}
+
+/// Generates `size` commands.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for _ in 0..size {
+        let command = rng.choose(&["forward", "down", "up"]);
+        s += &format!("{} {}\n", command, rng.between(1, 9));
+    }
+
+    s
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(s: &str) -> Result<Vec<Command>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<Command>, _params: &Params) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Command>, _params: &Params) -> i64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
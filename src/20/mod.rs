@@ -0,0 +1,338 @@
+//! Day 20: Trench Map
+
+use crate::animate::{self, Frames};
+use crate::gif::Movie;
+use crate::grid::Grid;
+use crate::image::{self, Image, Rgb};
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::fmt;
+
+#[derive(Clone, Copy)]
+pub struct Rules([bool; 512]);
+
+/// Reads a row of light (`#`) and dark (`.`) pixels.
+fn pixels<'a>(l: &mut Scanner<'a>) -> Result<&'a str, ParseError> {
+    let row = l.take_while(|c| c == '#' || c == '.');
+    if !l.is_done() {
+        return Err(l.error("'#' or '.'"));
+    }
+
+    Ok(row)
+}
+
+impl Rules {
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let mut l = Scanner::new(s, 1);
+        let row = pixels(&mut l)?;
+
+        if row.len() != 512 {
+            return Err(l.error_at(row.len().min(512), "512 pixels"));
+        }
+
+        let mut r = [false; 512];
+
+        for (i, c) in row.chars().enumerate() {
+            r[i] = c == '#';
+        }
+
+        Ok(Self(r))
+    }
+}
+
+impl fmt::Debug for Rules {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for x in self.0 {
+            write!(f, "{}", if x { '#' } else { '.' })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The image, which is infinite. Every pixel outside of the grid has the same
+/// value.
+#[derive(Clone)]
+pub struct Board {
+    image: Grid<bool>,
+    boundary_on: bool,
+}
+
+impl Board {
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            image: Grid::parse_pixels(s)?,
+            boundary_on: false,
+        })
+    }
+
+    fn neighbor_index(&self, (x, y): (isize, isize)) -> u16 {
+        let mut r = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                r <<= 1;
+                if self.is_on((x + dx, y + dy)) {
+                    r |= 1;
+                }
+            }
+        }
+
+        r
+    }
+
+    /// Whether a pixel is lit, with `(0, 0)` being the top left corner of the
+    /// finite part of the image.
+    pub fn is_on(&self, (x, y): (isize, isize)) -> bool {
+        if x < 0 || y < 0 {
+            return self.boundary_on;
+        }
+
+        match self.image.get((x as usize, y as usize)) {
+            Some(&on) => on,
+            None => self.boundary_on,
+        }
+    }
+
+    /// Enhances the image. The finite part grows by one pixel on every side.
+    pub fn step(&mut self, r: Rules) {
+        let mut image = Grid::filled(self.image.width() + 2, self.image.height() + 2, false);
+
+        for (x, y) in image.positions() {
+            let i = self.neighbor_index((x as isize - 1, y as isize - 1));
+            image[(x, y)] = r.0[i as usize];
+        }
+
+        self.image = image;
+
+        if self.boundary_on {
+            self.boundary_on = r.0[511];
+        } else {
+            self.boundary_on = r.0[0];
+        }
+    }
+
+    /// The number of lit pixels in the finite part of the image.
+    pub fn len(&self) -> usize {
+        self.image.iter().filter(|&&on| on).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn image(&self) -> &Grid<bool> {
+        &self.image
+    }
+}
+
+impl fmt::Debug for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.image.display_with(|&on| if on { '#' } else { '.' })
+        )
+    }
+}
+
+/// Parses the enhancement rules and the input image.
+pub fn parse(s: &str) -> Result<(Rules, Board), ParseError> {
+    let mut lines = s.splitn(3, '\n');
+
+    let rules = Rules::new(lines.next().unwrap_or(""))?;
+
+    match lines.next() {
+        Some("") => {}
+        Some(l) => return Err(Scanner::new(l, 2).error("an empty line")),
+        None => return Err(ParseError::end_of_input(1, "an empty line")),
+    }
+
+    let board = Board::new(lines.next().unwrap_or("")).map_err(|e| e.offset_lines(2))?;
+
+    Ok((rules, board))
+}
+
+pub const STEPS1: Param = Param {
+    name: "steps1",
+    default: 2,
+    min: 0,
+    max: 1000,
+    help: "enhancement steps in part 1",
+};
+
+pub const STEPS2: Param = Param {
+    name: "steps2",
+    default: 50,
+    min: 0,
+    max: 1000,
+    help: "enhancement steps in part 2",
+};
+
+/// Returns the number of lit pixels after enhancing the image the given
+/// number of times.
+pub fn enhance((rules, board): &(Rules, Board), steps: usize) -> usize {
+    let mut board = board.clone();
+
+    for _ in 0..steps {
+        board.step(*rules);
+    }
+
+    board.len()
+}
+
+pub fn part1(scan: &(Rules, Board)) -> usize {
+    enhance(scan, STEPS1.default as usize)
+}
+
+pub fn part2(scan: &(Rules, Board)) -> usize {
+    enhance(scan, STEPS2.default as usize)
+}
+
+/// Draws the finite part of the image with the lit pixels highlighted.
+pub fn render(board: &Board) -> String {
+    let mut s = String::new();
+
+    for row in board.image.rows() {
+        for run in row.chunk_by(|a, b| a == b) {
+            if run[0] {
+                s += &animate::highlight(&"#".repeat(run.len()));
+            } else {
+                s += &animate::dim(&".".repeat(run.len()));
+            }
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+/// The image at the start and after every enhancement step.
+pub fn history((rules, board): &(Rules, Board), steps: usize) -> impl Iterator<Item = Board> {
+    let (rules, mut board) = (*rules, board.clone());
+
+    std::iter::once(board.clone()).chain((0..steps).map(move |_| {
+        board.step(rules);
+        board.clone()
+    }))
+}
+
+/// The image after every enhancement step of part 2.
+pub fn frames(scan: &(Rules, Board), steps: usize) -> Frames<'static> {
+    Box::new(
+        history(scan, steps)
+            .enumerate()
+            .map(|(step, b)| format!("step {}  lit {}\n{}", step, b.len(), render(&b))),
+    )
+}
+
+/// Draws the finite part of the image after enhancing it the given number of
+/// times, with lit pixels in white.
+pub fn enhanced_image(scan: &(Rules, Board), steps: usize) -> Image {
+    let board = history(scan, steps).last().expect("the start is included");
+
+    board
+        .image
+        .map(|&on| if on { image::WHITE } else { image::BLACK })
+}
+
+/// The colours of `movie`: dark, lit, just turned dark and just lit. Lit
+/// pixels have odd indices.
+const PALETTE: [Rgb; 4] = [[0, 0, 0], [255, 255, 255], [140, 30, 30], [255, 190, 0]];
+
+/// The image after every enhancement step, all drawn at the size of the last
+/// one, with the pixels that changed in the step highlighted.
+pub fn movie(scan: &(Rules, Board), steps: usize) -> Movie {
+    let boards: Vec<Board> = history(scan, steps).collect();
+    let last = &boards[boards.len() - 1].image;
+    let (width, height) = (last.width(), last.height());
+
+    let mut frames: Vec<Grid<u8>> = Vec::new();
+
+    for (step, board) in boards.iter().enumerate() {
+        // every step grows the image by one pixel on each side
+        let offset = (steps - step) as isize;
+        let mut frame = Grid::filled(width, height, 0);
+
+        for (x, y) in frame.positions() {
+            let on = board.is_on((x as isize - offset, y as isize - offset));
+            let was_on = frames.last().map_or(on, |f: &Grid<u8>| f[(x, y)] % 2 == 1);
+
+            frame[(x, y)] = match (was_on, on) {
+                (false, true) => 3,
+                (true, false) => 2,
+                (_, on) => on as u8,
+            };
+        }
+
+        frames.push(frame);
+    }
+
+    Movie {
+        palette: PALETTE.to_vec(),
+        frames,
+    }
+}
+
+/// Generates random enhancement rules and a `size` by `size` image. The rules
+/// never light every pixel of the infinite image for good.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut rules: Vec<bool> = (0..512).map(|_| rng.chance(0.5)).collect();
+    if rules[0] {
+        rules[511] = false;
+    }
+
+    let pixel = |on: bool| if on { '#' } else { '.' };
+
+    let mut s: String = rules.into_iter().map(pixel).collect();
+    s += "\n\n";
+
+    for _ in 0..size.max(1) {
+        for _ in 0..size.max(1) {
+            s.push(pixel(rng.chance(0.5)));
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Input = (Rules, Board);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARAMS: &'static [Param] = &[STEPS1, STEPS2];
+
+    fn parse(s: &str) -> Result<(Rules, Board), ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &(Rules, Board), params: &Params) -> usize {
+        enhance(input, params.get(&STEPS1) as usize)
+    }
+
+    fn part2(input: &(Rules, Board), params: &Params) -> usize {
+        enhance(input, params.get(&STEPS2) as usize)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn frames<'a>(input: &'a (Rules, Board), params: &Params) -> Option<Frames<'a>> {
+        Some(frames(input, params.get(&STEPS2) as usize))
+    }
+
+    fn image(input: &(Rules, Board), params: &Params) -> Option<Image> {
+        Some(enhanced_image(input, params.get(&STEPS2) as usize))
+    }
+
+    fn movie(input: &(Rules, Board), params: &Params) -> Option<Movie> {
+        Some(movie(input, params.get(&STEPS2) as usize))
+    }
+}
@@ -0,0 +1,244 @@
+//! Day 21: Dirac Dice
+
+use crate::counter::{self, Counter, Overflow};
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// The deterministic 100-sided die.
+pub struct DetDie(i32);
+
+impl DetDie {
+    pub fn new() -> Self {
+        Self(1)
+    }
+
+    pub fn roll(&mut self) -> i32 {
+        let r = self.0;
+        self.0 = (self.0 % 100) + 1;
+        r
+    }
+}
+
+impl Default for DetDie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct State {
+    pub p1: i32,
+    pub p2: i32,
+    pub p1score: i32,
+    pub p2score: i32,
+}
+
+pub const SCORE1: Param = Param {
+    name: "score1",
+    default: 1000,
+    min: 1,
+    max: 1_000_000,
+    help: "score needed to win with the deterministic die in part 1",
+};
+
+pub const SCORE2: Param = Param {
+    name: "score2",
+    default: 21,
+    min: 1,
+    max: 1000,
+    help: "score needed to win with the Dirac die in part 2",
+};
+
+/// Counts the universes in which each player wins with the Dirac die, using
+/// `C` for the counts.
+pub struct Game<C = u64> {
+    target: i32,
+    mem: HashMap<State, (C, C)>,
+}
+
+impl<C: Counter> Game<C> {
+    /// A game won by the first player to reach `target` points.
+    pub fn new(target: i32) -> Self {
+        Self {
+            target,
+            mem: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of universes won by the player to move and by the
+    /// other player. `turn` is the number of turns played to reach `s` and
+    /// only used to report overflows.
+    ///
+    /// An overflow is reported in the turn whose universes were being added
+    /// up, together with those of every turn after it. These sums grow
+    /// towards the start of the game, so a target that is too high overflows
+    /// in one of the first turns, even though no single turn has that many
+    /// universes.
+    pub fn play(&mut self, s: State, turn: usize) -> Result<(C, C), Overflow> {
+        if let Some(r) = self.mem.get(&s) {
+            return Ok(r.clone());
+        }
+
+        if s.p2score >= self.target {
+            let r = (C::zero(), C::from_u64(1));
+            self.mem.insert(s, r.clone());
+            return Ok(r);
+        }
+
+        let mut res = (C::zero(), C::zero());
+
+        for (step, count) in [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)] {
+            let p1 = (s.p1 - 1 + step) % 10 + 1;
+            let (w2, w1) = self.play(
+                State {
+                    p1: s.p2,
+                    p2: p1,
+                    p1score: s.p2score,
+                    p2score: s.p1score + p1,
+                },
+                turn + 1,
+            )?;
+
+            let count = C::from_u64(count);
+            res.0 = counter::add(&res.0, &counter::mul(&count, &w1, turn + 1)?, turn + 1)?;
+            res.1 = counter::add(&res.1, &counter::mul(&count, &w2, turn + 1)?, turn + 1)?;
+        }
+
+        self.mem.insert(s, res.clone());
+        Ok(res)
+    }
+}
+
+impl<C: Counter> Default for Game<C> {
+    fn default() -> Self {
+        Self::new(SCORE2.default as i32)
+    }
+}
+
+/// Parses the starting positions of both players.
+pub fn parse(s: &str) -> Result<(i32, i32), ParseError> {
+    let mut lines = Scanner::lines(s);
+    let mut positions = [0; 2];
+
+    for (i, p) in positions.iter_mut().enumerate() {
+        let mut l = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(i, "a starting position"))?;
+
+        l.tag(&format!("Player {} starting position: ", i + 1))?;
+        let start = l.clone();
+        *p = l.number::<i32>()?;
+        if !(1..=10).contains(p) {
+            return Err(start.error("a position between 1 and 10"));
+        }
+        l.end()?;
+    }
+
+    Ok((positions[0], positions[1]))
+}
+
+/// Plays with the deterministic die until a player reaches `target` points
+/// and returns the losing score times the number of rolls. Every turn scores
+/// at least one point, so the product of at most `6 * target` rolls and a
+/// score below `target` fits in `u64`.
+pub fn practice((p1, p2): (i32, i32), target: i32) -> u64 {
+    let mut p1 = p1;
+    let mut p2 = p2;
+
+    let mut p1score = 0;
+    let mut p2score = 0;
+
+    let mut die = DetDie::new();
+
+    let mut rolls = 0;
+
+    loop {
+        let p1step = die.roll() + die.roll() + die.roll();
+        rolls += 3;
+        p1 = (p1 - 1 + p1step) % 10 + 1;
+        p1score += p1;
+
+        if p1score >= target {
+            break;
+        }
+
+        let p2step = die.roll() + die.roll() + die.roll();
+        rolls += 3;
+        p2 = (p2 - 1 + p2step) % 10 + 1;
+        p2score += p2;
+
+        if p2score >= target {
+            break;
+        }
+    }
+
+    let losing = if p1score > p2score { p2score } else { p1score };
+    rolls as u64 * losing as u64
+}
+
+/// Returns the number of universes won by the player who wins in more of
+/// them when playing to `target` points with the Dirac die, counted with `C`.
+pub fn dirac<C: Counter>((p1, p2): (i32, i32), target: i32) -> Result<C, Overflow> {
+    let mut game = Game::new(target);
+
+    let state = State {
+        p1,
+        p2,
+        p1score: 0,
+        p2score: 0,
+    };
+    let res = game.play(state, 0)?;
+
+    Ok(std::cmp::max(res.0, res.1))
+}
+
+pub fn part1(start: (i32, i32)) -> u64 {
+    practice(start, SCORE1.default as i32)
+}
+
+pub fn part2(start: (i32, i32)) -> Result<u64, Overflow> {
+    dirac(start, SCORE2.default as i32)
+}
+
+/// Generates random starting positions. There's nothing to scale, so `size`
+/// is ignored.
+pub fn generate(rng: &mut Rng, _size: usize) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.between(1, 10),
+        rng.between(1, 10)
+    )
+}
+
+/// The solver, counting the universes with `C`. Part 2 has no answer if
+/// the counts overflow.
+pub struct Day21<C = u64>(PhantomData<C>);
+
+impl<C: Counter> Solution for Day21<C> {
+    const DAY: u32 = 21;
+    type Input = (i32, i32);
+    type Answer1 = u64;
+    type Answer2 = Result<C, Overflow>;
+
+    const PARAMS: &'static [Param] = &[SCORE1, SCORE2];
+
+    fn parse(s: &str) -> Result<(i32, i32), ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &(i32, i32), params: &Params) -> u64 {
+        practice(*input, params.get(&SCORE1) as i32)
+    }
+
+    fn part2(input: &(i32, i32), params: &Params) -> Result<C, Overflow> {
+        dirac(*input, params.get(&SCORE2) as i32)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
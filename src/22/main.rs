@@ -0,0 +1,5 @@
+use aoc2021::{day22::Day22, solution};
+
+fn main() {
+    solution::main::<Day22>();
+}
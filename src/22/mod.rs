@@ -0,0 +1,235 @@
+//! Day 22: Reactor Reboot
+
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use crate::space::{Cuboid, Point3};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// A reboot step, which turns all cubes of a cuboid on or off.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+/// Reads the range of one axis, such as `x=-20..26`.
+fn range(l: &mut Scanner, axis: &str) -> Result<(i64, i64), ParseError> {
+    l.tag(axis)?;
+    l.tag("=")?;
+    let min = l.number()?;
+    l.tag("..")?;
+    let at = l.clone();
+    let max = l.number()?;
+
+    if max < min {
+        return Err(at.error(format!("a number of at least {}", min)));
+    }
+
+    Ok((min, max))
+}
+
+impl Step {
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        let on = if l.tag("on ").is_ok() {
+            true
+        } else {
+            l.tag("off ").map_err(|_| l.error("'on' or 'off'"))?;
+            false
+        };
+
+        let x = range(l, "x")?;
+        l.tag(",")?;
+        let y = range(l, "y")?;
+        l.tag(",")?;
+        let z = range(l, "z")?;
+        l.end()?;
+
+        Ok(Step {
+            on,
+            cuboid: Cuboid::new(Point3::new(x.0, y.0, z.0), Point3::new(x.1, y.1, z.1)),
+        })
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", if self.on { "on" } else { "off" }, self.cuboid)
+    }
+}
+
+impl std::str::FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Step::parse(&mut Scanner::new(s, 1))
+    }
+}
+
+pub fn parse(s: &str) -> Result<Vec<Step>, ParseError> {
+    let steps = Scanner::lines(s)
+        .map(|mut l| Step::parse(&mut l))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if steps.is_empty() {
+        return Err(ParseError::end_of_input(0, "a step"));
+    }
+
+    Ok(steps)
+}
+
+pub const REGION: Param = Param {
+    name: "region",
+    default: 50,
+    min: 0,
+    max: 1_000_000_000,
+    help: "distance from the origin along every axis that part 1 considers",
+};
+
+/// The number of cubes that are on after all steps, counted with signed
+/// intersections. Every cuboid that is on is counted once, and every
+/// overlap of a step with a counted cuboid is counted again with the
+/// opposite sign, so that the cubes of the step aren't counted at all
+/// before it turns them on.
+pub fn reboot(steps: &[Step]) -> i64 {
+    // the same overlap often comes up many times, so every cuboid keeps
+    // its total count
+    let mut counts: HashMap<Cuboid, i64> = HashMap::new();
+
+    for step in steps {
+        let mut update: HashMap<Cuboid, i64> = HashMap::new();
+
+        for (cuboid, &n) in &counts {
+            if let Some(overlap) = cuboid.intersection(&step.cuboid) {
+                *update.entry(overlap).or_insert(0) -= n;
+            }
+        }
+        if step.on {
+            *update.entry(step.cuboid).or_insert(0) += 1;
+        }
+
+        for (cuboid, n) in update {
+            *counts.entry(cuboid).or_insert(0) += n;
+        }
+        counts.retain(|_, n| *n != 0);
+    }
+
+    counts.iter().map(|(cuboid, n)| cuboid.volume() * n).sum()
+}
+
+/// Reboots the reactor like [`reboot`], but only for the cubes inside
+/// `region` and one cube at a time.
+pub fn reboot_naive(steps: &[Step], region: &Cuboid) -> usize {
+    let mut on = HashSet::new();
+
+    for step in steps {
+        let Some(cuboid) = step.cuboid.intersection(region) else {
+            continue;
+        };
+
+        for x in cuboid.min.x..=cuboid.max.x {
+            for y in cuboid.min.y..=cuboid.max.y {
+                for z in cuboid.min.z..=cuboid.max.z {
+                    if step.on {
+                        on.insert(Point3::new(x, y, z));
+                    } else {
+                        on.remove(&Point3::new(x, y, z));
+                    }
+                }
+            }
+        }
+    }
+
+    on.len()
+}
+
+/// The number of cubes that are on at most `region` away from the origin
+/// along every axis.
+pub fn part1(steps: &[Step], region: i64) -> i64 {
+    let region = Cuboid::around_origin(region);
+    let inside: Vec<Step> = steps
+        .iter()
+        .filter_map(|s| {
+            Some(Step {
+                on: s.on,
+                cuboid: s.cuboid.intersection(&region)?,
+            })
+        })
+        .collect();
+
+    reboot(&inside)
+}
+
+pub fn part2(steps: &[Step]) -> i64 {
+    reboot(steps)
+}
+
+/// A random cuboid inside `radius` of the origin that is up to `size` long
+/// along every axis.
+fn random_cuboid(rng: &mut Rng, radius: i64, size: i64) -> Cuboid {
+    let (mut min, mut max) = ([0; 3], [0; 3]);
+
+    for axis in 0..3 {
+        min[axis] = rng.between(-radius, radius);
+        max[axis] = rng.between(min[axis], (min[axis] + size - 1).min(radius));
+    }
+
+    Cuboid::new(min.into(), max.into())
+}
+
+/// Generates `size` steps like the puzzle input, which starts with up to 20
+/// steps in the initialization region and continues with large cuboids
+/// outside of it. The first step turns cubes on.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let region = Cuboid::around_origin(REGION.default as i64);
+
+    (0..size.max(1))
+        .map(|i| {
+            let cuboid = if i < 20 {
+                random_cuboid(rng, 50, 50)
+            } else {
+                loop {
+                    let c = random_cuboid(rng, 100_000, 60_000);
+                    if c.intersection(&region).is_none() {
+                        break c;
+                    }
+                }
+            };
+            let step = Step {
+                on: i == 0 || rng.chance(0.6),
+                cuboid,
+            };
+
+            format!("{}\n", step)
+        })
+        .collect()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Input = Vec<Step>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const PARAMS: &'static [Param] = &[REGION];
+
+    fn parse(s: &str) -> Result<Vec<Step>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<Step>, params: &Params) -> i64 {
+        part1(input, params.get(&REGION) as i64)
+    }
+
+    fn part2(input: &Vec<Step>, _params: &Params) -> i64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,160 @@
+//! Day 3: Binary Diagnostic
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+/// Parses the diagnostic report. All numbers must have the same number of
+/// bits, which can be at most 32.
+pub fn parse(s: &str) -> Result<Vec<String>, ParseError> {
+    let mut report: Vec<String> = Vec::new();
+
+    for mut l in Scanner::lines(s) {
+        let bits = l.take_while(|c| c == '0' || c == '1');
+
+        if bits.is_empty() || bits.len() > 32 {
+            return Err(l.error("up to 32 binary digits"));
+        }
+
+        match report.first() {
+            Some(first) if first.len() != bits.len() => {
+                return Err(l.error(format!("{} binary digits", first.len())));
+            }
+            _ => l.end()?,
+        }
+
+        report.push(bits.to_string());
+    }
+
+    if report.is_empty() {
+        return Err(ParseError::end_of_input(0, "binary digits"));
+    }
+
+    Ok(report)
+}
+
+pub fn part1(report: &[String]) -> usize {
+    let lines: Vec<&str> = report.iter().map(|l| l.as_str()).collect();
+
+    let (gamma, epsilon) = gammaepsilon(&lines, width(report));
+
+    gamma as usize * epsilon as usize
+}
+
+/// Number of bits per line in the report.
+fn width(report: &[String]) -> usize {
+    report.first().map_or(0, |l| l.len())
+}
+
+/// Computes the number made of the most common bit per
+/// bit position and the one with the least common bits.
+fn gammaepsilon(data: &[&str], linelen: usize) -> (u32, u32) {
+    let mut ones = [0; 32];
+    let mut zeroes = [0; 32];
+
+    for l in data {
+        for i in 0..linelen {
+            let c = l.chars().nth(i).expect("unexpected line end");
+            if c == '0' {
+                zeroes[i] += 1;
+            } else {
+                ones[i] += 1;
+            }
+        }
+    }
+
+    let mut gamma = 0;
+    for i in 0..linelen {
+        if ones[i] < zeroes[i] {
+            gamma <<= 1;
+        } else {
+            gamma = (gamma << 1) | 1;
+        }
+    }
+
+    let epsilon = !gamma & (u32::MAX >> (32 - linelen));
+
+    (gamma, epsilon)
+}
+
+pub fn part2(report: &[String]) -> u32 {
+    let mut oxy_numbers: Vec<&str> = report.iter().map(|l| l.as_str()).collect();
+    let mut co2_numbers = oxy_numbers.clone();
+    let linelen = width(report);
+
+    for pos in 0..linelen {
+        let (gamma, _epsilon) = gammaepsilon(&oxy_numbers, linelen);
+        let gamma_bit_set = ((gamma >> (linelen - pos - 1)) & 1) == 1;
+
+        oxy_numbers.retain(|x| (x.chars().nth(pos).unwrap() == '1') == gamma_bit_set);
+
+        if oxy_numbers.len() <= 1 {
+            break;
+        }
+    }
+
+    for pos in 0..linelen {
+        if co2_numbers.len() <= 1 {
+            break;
+        }
+
+        let (_, epsilon) = gammaepsilon(&co2_numbers, linelen);
+        let epsilon_bit_unset = ((epsilon >> (linelen - pos - 1)) & 1) == 0;
+
+        // when all numbers have the same bit, the least common one is the
+        // bit none of them has, and all numbers are kept
+        let kept: Vec<&str> = co2_numbers
+            .iter()
+            .copied()
+            .filter(|x| (x.chars().nth(pos).unwrap() == '0') == epsilon_bit_unset)
+            .collect();
+        if !kept.is_empty() {
+            co2_numbers = kept;
+        }
+    }
+
+    let oxy = u32::from_str_radix(oxy_numbers[0], 2).unwrap();
+    let co2 = u32::from_str_radix(co2_numbers[0], 2).unwrap();
+
+    oxy * co2
+}
+
+/// Generates a report of `size` 12-bit numbers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for _ in 0..size.max(1) {
+        for _ in 0..12 {
+            s.push(if rng.chance(0.5) { '1' } else { '0' });
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(s: &str) -> Result<Vec<String>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<String>, _params: &Params) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<String>, _params: &Params) -> u32 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,238 @@
+//! Day 4: Giant Squid
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Copy)]
+pub struct Board([u32; 25]);
+
+impl Board {
+    /// Parses the next five lines of five numbers each. `prev` is the number
+    /// of the line before the board.
+    fn parse<'a>(
+        lines: &mut impl Iterator<Item = Scanner<'a>>,
+        prev: usize,
+    ) -> Result<Self, ParseError> {
+        let mut b = [0; 25];
+
+        for r in 0..5 {
+            let mut l = lines
+                .next()
+                .ok_or_else(|| ParseError::end_of_input(prev + r, "a row of the board"))?;
+
+            for n in &mut b[r * 5..][..5] {
+                l.skip_whitespace();
+                *n = l.number::<u32>()?;
+            }
+
+            l.skip_whitespace();
+            l.end()?;
+        }
+
+        Ok(Self(b))
+    }
+
+    fn rows_won(&self, drawn: &[u32]) -> bool {
+        for r in 0..5 {
+            let off = r * 5;
+            let mut ncontained = 0;
+
+            for n in &self.0[off..][..5] {
+                if drawn.contains(n) {
+                    ncontained += 1;
+                }
+            }
+
+            if ncontained == 5 {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn columns_won(&self, drawn: &[u32]) -> bool {
+        for c in 0..5 {
+            let off = c;
+            let mut ncontained = 0;
+
+            for n in self.0[off..].iter().step_by(5) {
+                if drawn.contains(n) {
+                    ncontained += 1;
+                }
+            }
+
+            if ncontained == 5 {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn is_won(&self, drawn: &[u32]) -> bool {
+        let rowwon = self.rows_won(drawn);
+        let colwon = self.columns_won(drawn);
+        rowwon | colwon
+    }
+
+    pub fn score(&self, drawn: &[u32]) -> u32 {
+        let mut score = 0;
+
+        for n in &self.0 {
+            if !drawn.contains(n) {
+                score += n;
+            }
+        }
+
+        score
+    }
+}
+
+impl fmt::Debug for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..5 {
+            for x in 0..5 {
+                let off = 5 * y;
+                write!(f, "{:2} ", self.0[off + x])?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The numbers drawn in order and the boards playing along.
+#[derive(Clone, Debug)]
+pub struct Bingo {
+    pub numbers: Vec<u32>,
+    pub boards: Vec<Board>,
+}
+
+pub fn parse(s: &str) -> Result<Bingo, ParseError> {
+    let mut lines = Scanner::lines(s);
+
+    let mut first = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(0, "the drawn numbers"))?;
+    let numbers = first.separated(",", |l| l.number::<u32>())?;
+    first.end()?;
+
+    let mut boards = Vec::new();
+
+    // every board is preceded by an empty line
+    while let Some(l) = lines.next() {
+        l.end()?;
+        boards.push(Board::parse(&mut lines, l.lineno())?);
+    }
+
+    if boards.is_empty() {
+        return Err(ParseError::end_of_input(first.lineno(), "a board"));
+    }
+
+    Ok(Bingo { numbers, boards })
+}
+
+/// None of the boards wins with the numbers drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoWinner;
+
+impl fmt::Display for NoWinner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no board wins with the numbers drawn")
+    }
+}
+
+impl Error for NoWinner {}
+
+/// Score of the first board to win.
+pub fn part1(bingo: &Bingo) -> Result<u32, NoWinner> {
+    for l in 1..=bingo.numbers.len() {
+        let drawn = &bingo.numbers[..l];
+
+        for b in &bingo.boards {
+            if b.is_won(drawn) {
+                return Ok(b.score(drawn) * drawn[drawn.len() - 1]);
+            }
+        }
+    }
+
+    Err(NoWinner)
+}
+
+/// Score of the last board to win.
+pub fn part2(bingo: &Bingo) -> Result<u32, NoWinner> {
+    let mut scores = Vec::new();
+    let mut winners = Vec::new();
+
+    for l in 1..=bingo.numbers.len() {
+        let drawn = &bingo.numbers[..l];
+
+        for (i, b) in bingo.boards.iter().enumerate() {
+            if winners.contains(&i) {
+                continue;
+            }
+
+            if b.is_won(drawn) {
+                let score = b.score(drawn) * drawn[drawn.len() - 1];
+                scores.push(score);
+                winners.push(i);
+            }
+        }
+    }
+
+    scores.last().copied().ok_or(NoWinner)
+}
+
+/// Generates `size` boards. The numbers 0 to 99 are all drawn, so every board
+/// wins eventually.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut numbers);
+
+    let drawn: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    let mut s = drawn.join(",") + "\n";
+
+    for _ in 0..size.max(1) {
+        rng.shuffle(&mut numbers);
+        s.push('\n');
+
+        for row in numbers[..25].chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{:2}", n)).collect();
+            s += &row.join(" ");
+            s.push('\n');
+        }
+    }
+
+    s
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Bingo;
+    type Answer1 = Result<u32, NoWinner>;
+    type Answer2 = Result<u32, NoWinner>;
+
+    fn parse(s: &str) -> Result<Bingo, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Bingo, _params: &Params) -> Result<u32, NoWinner> {
+        part1(input)
+    }
+
+    fn part2(input: &Bingo, _params: &Params) -> Result<u32, NoWinner> {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,251 @@
+//! Day 5: Hydrothermal Venture
+
+use crate::grid::Grid;
+use crate::image::{self, Image};
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::cmp;
+use std::fmt;
+
+#[derive(Clone, Copy)]
+pub struct Line {
+    pub x1: i32,
+    pub y1: i32,
+    pub x2: i32,
+    pub y2: i32,
+}
+
+impl Line {
+    pub fn is_horizontal(&self) -> bool {
+        self.y1 == self.y2
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.x1 == self.x2
+    }
+
+    pub fn is_left_diagonal(&self) -> bool {
+        self.x2 - self.x1 == self.y2 - self.y1 && (self.x2 - self.x1 > 0) == (self.y2 - self.y1 > 0)
+    }
+
+    pub fn is_right_diagonal(&self) -> bool {
+        self.x2 - self.x1 == self.y2 - self.y1 && (self.x2 - self.x1 < 0) == (self.y2 - self.y1 > 0)
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        if self.is_horizontal() {
+            let low = cmp::min(self.x1, self.x2);
+            let high = cmp::max(self.x1, self.x2);
+            self.y1 == y && x <= high && x >= low
+        } else if self.is_vertical() {
+            let low = cmp::min(self.y1, self.y2);
+            let high = cmp::max(self.y1, self.y2);
+            self.x1 == x && y <= high && y >= low
+        } else {
+            false
+        }
+    }
+
+    pub fn contains_with_diagonal(&self, x: i32, y: i32) -> bool {
+        if self.is_horizontal() {
+            let low = cmp::min(self.x1, self.x2);
+            let high = cmp::max(self.x1, self.x2);
+            self.y1 == y && x <= high && x >= low
+        } else if self.is_vertical() {
+            let low = cmp::min(self.y1, self.y2);
+            let high = cmp::max(self.y1, self.y2);
+            self.x1 == x && y <= high && y >= low
+        } else if self.is_left_diagonal() {
+            // Low and high in this context are not the low and high
+            // numerical values but "lower" and "higher" on the board.
+            // e.g.: on the board:
+            // a.
+            // .b
+            // a is higher than b, even if it has the lower values
+            // in it's position.
+
+            let lowx = cmp::max(self.x1, self.x2);
+            let highx = cmp::min(self.x1, self.x2);
+            let highy = cmp::min(self.y1, self.y2);
+
+            x - highx == y - highy && x >= highx && x <= lowx
+        } else {
+            let lowx = cmp::min(self.x1, self.x2);
+            let highx = cmp::max(self.x1, self.x2);
+            let highy = cmp::min(self.y1, self.y2);
+
+            highx - x == y - highy && x >= lowx && x <= highx
+        }
+    }
+}
+
+impl fmt::Debug for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "({},{} -> {},{})", self.x1, self.y1, self.x2, self.y2)
+    }
+}
+
+impl Line {
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        let x1 = l.number::<i32>()?;
+        l.tag(",")?;
+        let y1 = l.number::<i32>()?;
+        l.tag(" -> ")?;
+        let x2 = l.number::<i32>()?;
+        l.tag(",")?;
+        let y2 = l.number::<i32>()?;
+        l.end()?;
+
+        Ok(Self { x1, y1, x2, y2 })
+    }
+}
+
+impl std::str::FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Line::parse(&mut Scanner::new(s, 1))
+    }
+}
+
+pub fn parse(s: &str) -> Result<Vec<Line>, ParseError> {
+    Scanner::lines(s).map(|mut l| Line::parse(&mut l)).collect()
+}
+
+/// Side of the square, starting at the origin, in which overlaps are
+/// counted.
+pub const SIZE: Param = Param {
+    name: "size",
+    default: 1000,
+    min: 1,
+    max: 10_000,
+    help: "side of the square in which overlapping vents are counted",
+};
+
+/// Counts the points in the `size` by `size` square that are covered by at
+/// least two vents.
+pub fn overlaps(vents: &[Line], size: i32, diagonals: bool) -> usize {
+    let mut overlaps = 0;
+
+    for x in 0..size {
+        for y in 0..size {
+            let count = vents
+                .iter()
+                .filter(|v| {
+                    if diagonals {
+                        v.contains_with_diagonal(x, y)
+                    } else {
+                        v.contains(x, y)
+                    }
+                })
+                .count();
+            if count >= 2 {
+                overlaps += 1;
+            }
+        }
+    }
+
+    overlaps
+}
+
+/// Counts the vents covering every point of the `size` by `size` square by
+/// walking along the lines. Lines that are neither horizontal, vertical nor
+/// diagonal at 45 degrees are left out.
+pub fn vent_map(vents: &[Line], size: usize, diagonals: bool) -> Grid<u32> {
+    let mut map = Grid::filled(size, size, 0);
+
+    for v in vents {
+        let (dx, dy) = (v.x2 - v.x1, v.y2 - v.y1);
+        let straight = v.is_horizontal() || v.is_vertical();
+        let diagonal = diagonals && dx.abs() == dy.abs();
+        if !straight && !diagonal {
+            continue;
+        }
+
+        for i in 0..=cmp::max(dx.abs(), dy.abs()) {
+            let (x, y) = (v.x1 + i * dx.signum(), v.y1 + i * dy.signum());
+            if let Some(count) = map.get_mut((x as usize, y as usize)) {
+                *count += 1;
+            }
+        }
+    }
+
+    map
+}
+
+/// Draws the number of vents per point of part 2 as a heatmap.
+pub fn heatmap(vents: &[Line], size: usize) -> Image {
+    let map = vent_map(vents, size, true);
+    let max = map.iter().copied().max().unwrap_or(0);
+
+    map.map(|&count| image::heat(count, max))
+}
+
+pub fn part1(vents: &[Line]) -> usize {
+    overlaps(vents, SIZE.default as i32, false)
+}
+
+pub fn part2(vents: &[Line]) -> usize {
+    overlaps(vents, SIZE.default as i32, true)
+}
+
+/// Generates `size` horizontal, vertical and diagonal lines inside the
+/// 1000x1000 area the solver looks at.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for _ in 0..size {
+        let (dx, dy) = *rng.choose(&[(1, 0), (0, 1), (1, 1), (1, -1)]);
+
+        let (x1, y1, x2, y2) = loop {
+            let (x1, y1) = (rng.between(0, 999), rng.between(0, 999));
+            let len = rng.between(1, 200);
+            let (x2, y2) = (x1 + dx * len, y1 + dy * len);
+
+            if (0..1000).contains(&x2) && (0..1000).contains(&y2) {
+                break (x1, y1, x2, y2);
+            }
+        };
+
+        if rng.chance(0.5) {
+            s += &format!("{},{} -> {},{}\n", x1, y1, x2, y2);
+        } else {
+            s += &format!("{},{} -> {},{}\n", x2, y2, x1, y1);
+        }
+    }
+
+    s
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARAMS: &'static [Param] = &[SIZE];
+
+    fn parse(s: &str) -> Result<Vec<Line>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<Line>, params: &Params) -> usize {
+        overlaps(input, params.get(&SIZE) as i32, false)
+    }
+
+    fn part2(input: &Vec<Line>, params: &Params) -> usize {
+        overlaps(input, params.get(&SIZE) as i32, true)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn image(input: &Vec<Line>, params: &Params) -> Option<Image> {
+        Some(heatmap(input, params.get(&SIZE) as usize))
+    }
+}
@@ -0,0 +1,231 @@
+//! Day 6: Lanternfish
+
+use crate::animate::{self, Frames};
+use crate::counter::{Counter, Overflow};
+use crate::params::{Param, Params};
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+use std::marker::PhantomData;
+
+/// The number of fish per timer value.
+#[derive(Clone, Debug)]
+pub struct Population<C = u64>([C; 9]);
+
+impl Population {
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        let mut p = [0; 9];
+
+        let timers = l.separated(",", |l| {
+            let start = l.clone();
+            match l.number::<usize>()? {
+                num if num < 9 => Ok(num),
+                _ => Err(start.error("a timer between 0 and 8")),
+            }
+        })?;
+        l.end()?;
+
+        for num in timers {
+            p[num] += 1;
+        }
+
+        Ok(Self(p))
+    }
+
+    /// The same population counted with another type.
+    pub fn to_counter<C: Counter>(&self) -> Population<C> {
+        Population(self.0.map(C::from_u64))
+    }
+}
+
+impl std::str::FromStr for Population {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Population::parse(&mut Scanner::new(s, 1))
+    }
+}
+
+impl<C: Counter> Population<C> {
+    /// Returns the population on the next day, or `None` if the counts
+    /// overflow.
+    pub fn advance(&self) -> Option<Self> {
+        let old = &self.0;
+        let new = [
+            old[1].clone(),               // 0
+            old[2].clone(),               // 1
+            old[3].clone(),               // 2
+            old[4].clone(),               // 3
+            old[5].clone(),               // 4
+            old[6].clone(),               // 5
+            old[7].checked_add(&old[0])?, // 6
+            old[8].clone(),               // 7
+            old[0].clone(),               // 8
+        ];
+
+        Some(Self(new))
+    }
+
+    /// The total number of fish, or `None` if it overflows.
+    pub fn size(&self) -> Option<C> {
+        self.0
+            .iter()
+            .try_fold(C::zero(), |sum, n| sum.checked_add(n))
+    }
+}
+
+pub fn parse(s: &str) -> Result<Population, ParseError> {
+    match Scanner::lines(s).next() {
+        Some(mut l) => Population::parse(&mut l),
+        None => Err(ParseError::end_of_input(0, "a timer between 0 and 8")),
+    }
+}
+
+pub const DAYS1: Param = Param {
+    name: "days1",
+    default: 80,
+    min: 0,
+    max: 1_000_000,
+    help: "days simulated in part 1",
+};
+
+pub const DAYS2: Param = Param {
+    name: "days2",
+    default: 256,
+    min: 0,
+    max: 1_000_000,
+    help: "days simulated in part 2",
+};
+
+/// Returns the number of fish after the given number of days, counted with
+/// `C`.
+pub fn simulate<C: Counter>(population: &Population, days: usize) -> Result<C, Overflow> {
+    let mut population = population.to_counter::<C>();
+    let overflow = |step| Overflow {
+        counter: C::NAME,
+        step,
+    };
+
+    let mut size = population.size().ok_or(overflow(0))?;
+
+    for day in 1..=days {
+        population = population.advance().ok_or(overflow(day))?;
+        size = population.size().ok_or(overflow(day))?;
+    }
+
+    Ok(size)
+}
+
+/// Simulates every fish on its own. The number of fish grows exponentially,
+/// so this is only feasible for a few days and serves as a reference for
+/// `Population`.
+pub fn simulate_naive(timers: &[u8], days: usize) -> usize {
+    let mut fish = timers.to_vec();
+
+    for _ in 0..days {
+        let mut born = 0;
+
+        for timer in fish.iter_mut() {
+            if *timer == 0 {
+                *timer = 6;
+                born += 1;
+            } else {
+                *timer -= 1;
+            }
+        }
+
+        fish.extend(std::iter::repeat_n(8, born));
+    }
+
+    fish.len()
+}
+
+pub fn part1(population: &Population) -> Result<u64, Overflow> {
+    simulate(population, DAYS1.default as usize)
+}
+
+pub fn part2(population: &Population) -> Result<u64, Overflow> {
+    simulate(population, DAYS2.default as usize)
+}
+
+/// Width of the longest bar in `render`.
+const BAR: usize = 50;
+
+/// Draws the number of fish per timer as bars, with the newborn fish
+/// highlighted.
+pub fn render(population: &Population) -> String {
+    let max = population.0.iter().copied().max().unwrap_or(0).max(1);
+    let mut s = String::new();
+
+    for (timer, &n) in population.0.iter().enumerate() {
+        let len = (n as u128 * BAR as u128).div_ceil(max as u128) as usize;
+        let bar = "#".repeat(len);
+        let bar = if timer == 8 {
+            animate::highlight(&bar)
+        } else {
+            bar
+        };
+
+        s += &format!("{}  {}{}  {}\n", timer, bar, " ".repeat(BAR - len), n);
+    }
+
+    s
+}
+
+/// The population on every day of part 2, until the counts overflow.
+pub fn frames(population: &Population, days: usize) -> Frames<'static> {
+    let mut p = Some(population.clone());
+
+    Box::new((0..=days).map_while(move |day| {
+        let current = p.take()?;
+        let frame = format!(
+            "day {}  fish {}\n{}",
+            day,
+            current.size()?,
+            render(&current)
+        );
+        p = current.advance();
+        Some(frame)
+    }))
+}
+
+/// Generates `size` fish with timers from 1 to 5.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let timers: Vec<String> = (0..size.max(1))
+        .map(|_| rng.between(1, 5).to_string())
+        .collect();
+
+    timers.join(",") + "\n"
+}
+
+/// The solver, counting the fish with `C`. Parts whose counts overflow
+/// have no answer.
+pub struct Day6<C = u64>(PhantomData<C>);
+
+impl<C: Counter> Solution for Day6<C> {
+    const DAY: u32 = 6;
+    type Input = Population;
+    type Answer1 = Result<C, Overflow>;
+    type Answer2 = Result<C, Overflow>;
+
+    const PARAMS: &'static [Param] = &[DAYS1, DAYS2];
+
+    fn parse(s: &str) -> Result<Population, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Population, params: &Params) -> Result<C, Overflow> {
+        simulate(input, params.get(&DAYS1) as usize)
+    }
+
+    fn part2(input: &Population, params: &Params) -> Result<C, Overflow> {
+        simulate(input, params.get(&DAYS2) as usize)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn frames<'a>(input: &'a Population, params: &Params) -> Option<Frames<'a>> {
+        Some(frames(input, params.get(&DAYS2) as usize))
+    }
+}
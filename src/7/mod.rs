@@ -0,0 +1,82 @@
+//! Day 7: The Treachery of Whales
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+pub fn parse(s: &str) -> Result<Vec<i64>, ParseError> {
+    let mut l = match Scanner::lines(s).next() {
+        Some(l) => l,
+        None => return Err(ParseError::end_of_input(0, "a number")),
+    };
+
+    let crabs = l.separated(",", |l| l.number::<i64>())?;
+    l.end()?;
+
+    Ok(crabs)
+}
+
+pub fn part1(crabs: &[i64]) -> i64 {
+    let minpos = *crabs.iter().min().unwrap();
+    let maxpos = *crabs.iter().max().unwrap();
+
+    (minpos..=maxpos)
+        .map(|p| crabs.iter().map(|x| i64::abs(x - p)).sum())
+        .min()
+        .unwrap()
+}
+
+pub fn part2(crabs: &[i64]) -> i64 {
+    let minpos = *crabs.iter().min().unwrap();
+    let maxpos = *crabs.iter().max().unwrap();
+
+    (minpos..=maxpos)
+        .map(|p| {
+            crabs
+                .iter()
+                .map(|x| {
+                    let diff = i64::abs(x - p);
+
+                    // Gauss sum
+                    (diff * diff + diff) / 2
+                })
+                .sum()
+        })
+        .min()
+        .unwrap()
+}
+
+/// Generates `size` crabs at positions below 2000.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let crabs: Vec<String> = (0..size.max(1))
+        .map(|_| rng.between(0, 1999).to_string())
+        .collect();
+
+    crabs.join(",") + "\n"
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(s: &str) -> Result<Vec<i64>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<i64>, _params: &Params) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i64>, _params: &Params) -> i64 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
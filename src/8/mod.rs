@@ -0,0 +1,204 @@
+//! Day 8: Seven Segment Search
+
+use crate::params::Params;
+use crate::parse::{ParseError, Scanner};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+/// One display: the ten unique signal patterns and the four digit output.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub patterns: Vec<String>,
+    pub output: Vec<String>,
+}
+
+/// Reads `count` space separated patterns of the segments `a` to `g`.
+fn segments(l: &mut Scanner, count: usize) -> Result<Vec<String>, ParseError> {
+    let mut patterns = Vec::new();
+
+    for _ in 0..count {
+        l.skip_whitespace();
+
+        let p = l.take_while(|c| ('a'..='g').contains(&c));
+        if p.is_empty() {
+            return Err(l.error("a pattern of the segments a to g"));
+        }
+
+        patterns.push(p.to_string());
+    }
+
+    Ok(patterns)
+}
+
+impl Entry {
+    fn parse(l: &mut Scanner) -> Result<Self, ParseError> {
+        let start = l.clone();
+        let patterns = segments(l, 10)?;
+
+        let mut known = Vec::new();
+        for len in [2, 4] {
+            match patterns.iter().find(|p| p.len() == len) {
+                Some(p) => known.push(p.as_str()),
+                None => {
+                    let mut e = start.error("the patterns of the digits 1 and 4");
+                    e.found = format!("no pattern of {} segments", len);
+                    return Err(e);
+                }
+            }
+        }
+
+        l.tag(" |")?;
+        let mut output = Vec::new();
+        for _ in 0..4 {
+            l.skip_whitespace();
+            let digit = l.clone();
+            let p = segments(l, 1)?.remove(0);
+
+            if decode(&p, known[0], known[1]).is_none() {
+                let mut e = digit.error("the pattern of a digit");
+                e.found = format!("'{}'", p);
+                return Err(e);
+            }
+            output.push(p);
+        }
+        l.end()?;
+
+        Ok(Self { patterns, output })
+    }
+
+    /// The pattern of the digit 1 or 4, whose segments tell the others
+    /// apart. Parsing checks that there is one.
+    fn known(&self, len: usize) -> &str {
+        self.patterns
+            .iter()
+            .find(|p| p.len() == len)
+            .expect("parse checks the patterns of 1 and 4")
+    }
+}
+
+pub fn parse(s: &str) -> Result<Vec<Entry>, ParseError> {
+    Scanner::lines(s)
+        .map(|mut l| Entry::parse(&mut l))
+        .collect()
+}
+
+pub fn part1(entries: &[Entry]) -> usize {
+    let mut digits = 0;
+
+    for e in entries {
+        for o in &e.output {
+            if [2, 3, 4, 7].contains(&o.len()) {
+                digits += 1;
+            }
+        }
+    }
+
+    digits
+}
+
+fn overlap_len(a: &str, b: &str) -> usize {
+    b.chars().filter(|&c| a.contains(c)).count()
+}
+
+/// The digit of a pattern, told apart by its length and the segments it
+/// shares with the patterns of 1 and 4, or `None` if no digit fits.
+fn decode(digit: &str, one: &str, four: &str) -> Option<i32> {
+    match (
+        digit.len(),
+        overlap_len(one, digit),
+        overlap_len(four, digit),
+    ) {
+        (2, 2, 2) => Some(1),
+        (5, 1, 2) => Some(2),
+        (5, 2, 3) => Some(3),
+        (4, 2, 4) => Some(4),
+        (5, 1, 3) => Some(5),
+        (6, 1, 3) => Some(6),
+        (3, 2, 2) => Some(7),
+        (7, 2, 4) => Some(8),
+        (6, 2, 4) => Some(9),
+        (6, 2, 3) => Some(0),
+        _ => None,
+    }
+}
+
+pub fn part2(entries: &[Entry]) -> i32 {
+    let mut s = 0;
+
+    for e in entries {
+        let (one, four) = (e.known(2), e.known(4));
+
+        let mut out = 0;
+        for digit in &e.output {
+            let n = decode(digit, one, four).expect("parse checks the output digits");
+            out = out * 10 + n;
+        }
+        s += out;
+    }
+
+    s
+}
+
+/// The segments of every digit on a correctly wired display.
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// The pattern of a digit on a display with the given wiring, with its
+/// segments in random order.
+fn wired(rng: &mut Rng, wires: &[char], digit: usize) -> String {
+    let mut p: Vec<char> = DIGITS[digit]
+        .bytes()
+        .map(|b| wires[(b - b'a') as usize])
+        .collect();
+    rng.shuffle(&mut p);
+    p.into_iter().collect()
+}
+
+/// Generates `size` displays, each with its own random wiring.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for _ in 0..size {
+        let mut wires: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wires);
+
+        let mut patterns: Vec<String> = (0..10).map(|d| wired(rng, &wires, d)).collect();
+        let output: Vec<String> = (0..4)
+            .map(|_| {
+                let d = rng.below(10);
+                wired(rng, &wires, d)
+            })
+            .collect();
+        rng.shuffle(&mut patterns);
+
+        s += &format!("{} | {}\n", patterns.join(" "), output.join(" "));
+    }
+
+    s
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(s: &str) -> Result<Vec<Entry>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Vec<Entry>, _params: &Params) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Entry>, _params: &Params) -> i32 {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,145 @@
+//! Day 9: Smoke Basin
+
+use crate::grid::Grid;
+use crate::image::{self, Image};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+pub fn parse(s: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(s)
+}
+
+pub fn part1(board: &Grid<u8>) -> u64 {
+    let mut s: u64 = 0;
+
+    for p in board.positions() {
+        let height = board[p];
+
+        let lowpoint = board.neighbors4(p).all(|n| board[n] > height);
+
+        if lowpoint {
+            s += height as u64 + 1;
+        }
+    }
+
+    s
+}
+
+fn flood(board: &Grid<u8>, basins: &mut Grid<u32>, start: (usize, usize), id: u32) {
+    let mut queue = vec![start];
+
+    while let Some(current) = queue.pop() {
+        if board[current] == 9 {
+            continue;
+        }
+
+        basins[current] = id;
+
+        for n in board.neighbors4(current) {
+            if basins[n] == 0 {
+                queue.push(n);
+            }
+        }
+    }
+}
+
+/// Labels every location with the id of the basin it belongs to, starting
+/// at one. Locations of height 9 belong to no basin and are labelled 0.
+/// Returns the labels and the number of basins.
+pub fn basins(board: &Grid<u8>) -> (Grid<u32>, u32) {
+    // Maps locations to basins, 0 means no basin assigned
+    let mut basins = Grid::filled(board.width(), board.height(), 0);
+
+    let mut next_basin: u32 = 1; // id of next basin
+
+    for p in board.positions() {
+        if basins[p] == 0 && board[p] != 9 {
+            flood(board, &mut basins, p, next_basin);
+            next_basin += 1;
+        }
+    }
+
+    (basins, next_basin - 1)
+}
+
+/// The product of the sizes of the three largest basins, or of all basins if
+/// there are fewer.
+pub fn part2(board: &Grid<u8>) -> usize {
+    let (basins, count) = basins(board);
+
+    let mut sizes = vec![0; count as usize + 1];
+    for &id in basins.iter() {
+        sizes[id as usize] += 1;
+    }
+
+    // label 0 is the locations of height 9
+    let mut sizes = sizes.split_off(1);
+    sizes.sort_unstable();
+
+    sizes.iter().rev().take(3).product()
+}
+
+/// Draws every basin in its own colour and the locations of height 9 in
+/// black.
+pub fn basin_image(board: &Grid<u8>) -> Image {
+    basins(board).0.map(|&id| {
+        if id == 0 {
+            image::BLACK
+        } else {
+            image::palette(id)
+        }
+    })
+}
+
+/// Generates a `size` by `size` heightmap. Ridges of height 9 split it into
+/// basins of up to 5 by 5 locations, so there are three basins from a size
+/// of 11 on.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+
+    for y in 0..size.max(1) {
+        for x in 0..size.max(1) {
+            let ridge = x % 6 == 5 || y % 6 == 5;
+            let height = if ridge || rng.chance(0.05) {
+                9
+            } else {
+                rng.between(0, 8)
+            };
+            s += &height.to_string();
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    type Input = Grid<u8>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(s: &str) -> Result<Grid<u8>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &Grid<u8>, _params: &Params) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<u8>, _params: &Params) -> usize {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    fn image(input: &Grid<u8>, _params: &Params) -> Option<Image> {
+        Some(basin_image(input))
+    }
+}
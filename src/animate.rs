@@ -0,0 +1,234 @@
+//! Replays step-by-step simulations in the terminal with ANSI escape codes.
+//!
+//! A day draws every state of its simulation as a frame of text and the
+//! player shows the frames one after the other. While playing, space pauses,
+//! `n` shows the next frame, `+` and `-` change the speed and `q` or Ctrl+C
+//! quits.
+
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The frames of an animation. Every frame is a full drawing of one state,
+/// one line per row, and may contain colours.
+pub type Frames<'a> = Box<dyn Iterator<Item = String> + 'a>;
+
+const HOME: &str = "\x1b[H";
+const CLEAR: &str = "\x1b[2J";
+const CLEAR_LINE: &str = "\x1b[K";
+const CLEAR_BELOW: &str = "\x1b[J";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+/// The key Ctrl+C sends.
+const INTERRUPT: u8 = 0x03;
+
+const MIN_FPS: f64 = 0.25;
+const MAX_FPS: f64 = 1000.0;
+
+/// Shows text in bold yellow.
+pub fn highlight(s: &str) -> String {
+    format!("\x1b[1;33m{}\x1b[0m", s)
+}
+
+/// Shows text in a faint colour.
+pub fn dim(s: &str) -> String {
+    format!("\x1b[2m{}\x1b[0m", s)
+}
+
+/// A key pressed while playing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    /// Pauses or resumes.
+    Pause,
+    /// Shows the next frame and pauses.
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    pub fn from_key(key: u8) -> Option<Self> {
+        match key {
+            b' ' | b'p' => Some(Self::Pause),
+            b'n' | b'.' => Some(Self::Step),
+            b'+' | b'=' => Some(Self::Faster),
+            b'-' => Some(Self::Slower),
+            // Ctrl+C arrives as a key, as raw mode turns off its signal
+            b'q' | 0x1b | INTERRUPT => Some(Self::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// The speed of the player and whether it starts paused.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Playback {
+    pub fps: f64,
+    pub paused: bool,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            fps: 10.0,
+            paused: false,
+        }
+    }
+}
+
+impl Playback {
+    fn delay(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    fn status(&self, frame: usize) -> String {
+        format!(
+            "frame {}  {} fps{}  [space] pause  [n] step  [+/-] speed  [q] quit",
+            frame,
+            self.fps,
+            if self.paused { "  paused" } else { "" }
+        )
+    }
+}
+
+/// Waits until the next frame is due, or for a key while paused.
+enum Wait {
+    Next,
+    Key(Control),
+}
+
+fn wait(controls: &Receiver<Control>, deadline: Option<Instant>) -> Wait {
+    match deadline {
+        // without a keyboard nobody can resume, so keep playing
+        None => controls.recv().map_or(Wait::Next, Wait::Key),
+        Some(deadline) => {
+            let left = deadline.saturating_duration_since(Instant::now());
+            match controls.recv_timeout(left) {
+                Ok(c) => Wait::Key(c),
+                Err(RecvTimeoutError::Timeout) => Wait::Next,
+                Err(RecvTimeoutError::Disconnected) => {
+                    thread::sleep(left);
+                    Wait::Next
+                }
+            }
+        }
+    }
+}
+
+fn draw(out: &mut impl Write, frame: &str, status: &str) -> io::Result<()> {
+    let mut s = String::from(HOME);
+    for line in frame.lines() {
+        s += line;
+        s += CLEAR_LINE;
+        s += "\n";
+    }
+    s += &dim(status);
+    s += CLEAR_BELOW;
+
+    out.write_all(s.as_bytes())?;
+    out.flush()
+}
+
+/// Plays the frames on `out` and returns the number of frames shown. The
+/// keys are read from `controls`. Once that is disconnected the animation
+/// plays to the end.
+pub fn play(
+    frames: Frames,
+    out: &mut impl Write,
+    controls: &Receiver<Control>,
+    mut playback: Playback,
+) -> io::Result<usize> {
+    write!(out, "{}{}", HIDE_CURSOR, CLEAR)?;
+
+    let mut shown = 0;
+
+    'frames: for frame in frames {
+        shown += 1;
+        draw(out, &frame, &playback.status(shown))?;
+
+        let deadline = Instant::now() + playback.delay();
+        loop {
+            let control = match wait(controls, (!playback.paused).then_some(deadline)) {
+                Wait::Next => break,
+                Wait::Key(c) => c,
+            };
+
+            match control {
+                Control::Pause => playback.paused = !playback.paused,
+                Control::Step => {
+                    playback.paused = true;
+                    break;
+                }
+                Control::Faster => playback.fps = (playback.fps * 2.0).min(MAX_FPS),
+                Control::Slower => playback.fps = (playback.fps / 2.0).max(MIN_FPS),
+                Control::Quit => break 'frames,
+            }
+
+            draw(out, &frame, &playback.status(shown))?;
+        }
+    }
+
+    writeln!(out, "{}", SHOW_CURSOR)?;
+    Ok(shown)
+}
+
+/// Puts the terminal into a mode where keys are read without waiting for
+/// enter and without echo, until dropped. Ctrl+C is read as a key instead of
+/// interrupting the program, so the player can quit and restore the terminal.
+pub struct RawMode {
+    saved: String,
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let out = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+
+    out.status
+        .success()
+        .then(|| String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+impl RawMode {
+    /// Returns `None` if stdin is not a terminal or the mode can't be set.
+    pub fn enable() -> Option<Self> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Some(Self { saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+
+/// Reads keys from stdin on a separate thread. The receiver is disconnected
+/// at the end of the input.
+pub fn keyboard() -> Receiver<Control> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for key in io::stdin().lock().bytes() {
+            let Ok(key) = key else { break };
+            if let Some(c) = Control::from_key(key) {
+                if tx.send(c).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    rx
+}
@@ -0,0 +1,150 @@
+//! The manifest of known answers in `answers.toml`.
+//!
+//! The manifest is a list of `[[answer]]` tables, one per day and input file:
+//!
+//! ```toml
+//! [[answer]]
+//! day = 1
+//! input = "src/1/data.txt"
+//! part1 = "1121"
+//! part2 = "1065"
+//! ```
+//!
+//! A part without a recorded answer is left out. Only this subset of TOML is
+//! supported: tables of the `[[answer]]` array, integers, strings with the
+//! escapes `\"`, `\\` and `\n`, for answers that span lines, and comments.
+
+use crate::parse::{ParseError, Scanner};
+
+/// Path of the manifest, relative to the repository root.
+pub const MANIFEST: &str = "answers.toml";
+
+/// The expected answer of one part of a day for one input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub expected: String,
+}
+
+#[derive(Default)]
+struct Table {
+    line: usize,
+    day: Option<u32>,
+    input: Option<String>,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Table {
+    fn finish(self, answers: &mut Vec<Answer>) -> Result<(), ParseError> {
+        let missing = |key: &str| {
+            ParseError::new(self.line, 1, format!("'{}' in table", key), "end of table")
+        };
+
+        let day = self.day.ok_or_else(|| missing("day"))?;
+        let input = self.input.ok_or_else(|| missing("input"))?;
+
+        for (part, expected) in [(1, self.part1), (2, self.part2)] {
+            if let Some(expected) = expected {
+                answers.push(Answer {
+                    day,
+                    part,
+                    input: input.clone(),
+                    expected,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn string(l: &mut Scanner) -> Result<String, ParseError> {
+    l.tag("\"")?;
+    let mut s = String::new();
+
+    loop {
+        s += l.take_while(|c| c != '"' && c != '\\');
+        if l.tag("\\").is_err() {
+            break;
+        }
+
+        let escape = l.clone();
+        match l.next_char() {
+            Some('"') => s.push('"'),
+            Some('\\') => s.push('\\'),
+            Some('n') => s.push('\n'),
+            _ => return Err(escape.error("'\"', '\\' or 'n' after '\\'")),
+        }
+    }
+
+    l.tag("\"")?;
+    Ok(s)
+}
+
+pub fn parse(s: &str) -> Result<Vec<Answer>, ParseError> {
+    let mut answers = Vec::new();
+    let mut table: Option<Table> = None;
+
+    for mut l in Scanner::lines(s) {
+        l.skip_whitespace();
+        if l.is_done() || l.peek() == Some('#') {
+            continue;
+        }
+
+        if l.tag("[[answer]]").is_ok() {
+            if let Some(t) = table.take() {
+                t.finish(&mut answers)?;
+            }
+
+            table = Some(Table {
+                line: l.lineno(),
+                ..Table::default()
+            });
+        } else {
+            let t = table.as_mut().ok_or_else(|| l.error("'[[answer]]'"))?;
+
+            let start = l.clone();
+            let key = l.take_while(|c| c.is_ascii_alphanumeric());
+            l.skip_whitespace();
+            l.tag("=")?;
+            l.skip_whitespace();
+
+            match key {
+                "day" => t.day = Some(l.number::<u32>()?),
+                "input" => t.input = Some(string(&mut l)?),
+                "part1" => t.part1 = Some(string(&mut l)?),
+                "part2" => t.part2 = Some(string(&mut l)?),
+                _ => return Err(start.error("'day', 'input', 'part1' or 'part2'")),
+            }
+        }
+
+        l.skip_whitespace();
+        if l.peek() != Some('#') {
+            l.end()?;
+        }
+    }
+
+    if let Some(t) = table {
+        t.finish(&mut answers)?;
+    }
+
+    Ok(answers)
+}
+
+/// Reads the manifest at `path`. A missing manifest has no answers.
+pub fn load(path: &str) -> Result<Vec<Answer>, ParseError> {
+    match std::fs::read_to_string(path) {
+        Ok(s) => parse(&s).map_err(|e| e.with_file(path)),
+        Err(_) => Ok(Vec::new()),
+    }
+}
+
+/// Looks up the expected answer for a part of a day and an input file.
+pub fn find<'a>(answers: &'a [Answer], day: u32, part: u32, input: &str) -> Option<&'a Answer> {
+    answers
+        .iter()
+        .find(|a| a.day == day && a.part == part && a.input == input)
+}
@@ -0,0 +1,767 @@
+use aoc2021::animate::{self, Playback};
+use aoc2021::answers::{self, Answer};
+use aoc2021::bench::{self, Stats, Timings};
+use aoc2021::days::{self, Day};
+use aoc2021::image::{self, Encoding};
+use aoc2021::input;
+use aoc2021::params::{self, Params};
+use aoc2021::pool;
+use aoc2021::report::{self, Format, Record};
+use aoc2021::rng::Rng;
+use aoc2021::scaffold;
+use aoc2021::solution::Solved;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: aoc <command>
+
+commands:
+    run <day> [part]    run both parts of a day, or only the given part
+    list                list all days, whether they are implemented and
+                        their parameters with the puzzle's values
+    all                 run every implemented day in parallel and print a
+                        summary, including the days that failed and the
+                        ones without puzzle input
+    verify [manifest]   check all days against the recorded answers in
+                        answers.toml, or the given manifest
+    bench [day...]      time parsing and both parts of the given days, or of
+                        all days, on their puzzle input
+    generate <day>      write a random input for a day
+    animate <day>       replay the simulation of day 6, 11, 17 or 20 in the
+                        terminal; space pauses, n steps, + and - change the
+                        speed and q or Ctrl+C quits
+    image <day>         draw day 5, 9, 13, 15 or 20 into a .ppm or .png
+                        file given with -o
+    gif <day>           record every step of day 11 or 20 into an animated
+                        .gif file given with -o
+    new <day> [title]   add a day from a template: its module and binary
+                        in src/N/, empty inputs, and its entries in
+                        Cargo.toml, the runner, tests/samples.rs and
+                        answers.toml
+
+options:
+    -i, --input <path>  read the input of `run`, `animate`, `image` or `gif`
+                        from a file, or stdin for `-`
+    -p, --param <name=value>
+                        change a parameter of the day for `run`, `animate`,
+                        `image` or `gif`, such as
+                        the number of steps; may be repeated
+    --big               count with integers of arbitrary size in `run` of
+                        day 6, 14 or 21, whose counts overflow for larger
+                        parameters
+    --format <format>   print the answers of `run` and `all` as `text` or as
+                        `json` records (default: text)
+    -j, --jobs <n>      number of threads for `all` (default: one per CPU)
+    -n, --runs <n>      number of runs per day for `bench` (default: 10)
+    -s, --seed <n>      seed of the random input for `generate` (default: 0)
+    --size <n>          size of the random input for `generate`, usually
+                        the number of lines or the side of the map
+                        (default: 100)
+    -o, --output <path> write the random input to a file instead of stdout,
+                        or the file to write the picture of `image` or
+                        `gif` to
+    --fps <n>           frames per second of `animate` (default: 10)
+    --paused            start `animate` paused
+    --scale <n>         draw every cell of `image` or `gif` as n by n pixels
+                        (default: 1 for `image`, 4 for `gif`)
+    --delay <n>         hundredths of a second per frame of `gif`
+                        (default: 10)
+    --json <path>       also write the `bench` results as JSON to a file, or
+                        to stdout for `-`";
+
+/// Removes a flag from the arguments and returns whether it was given.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|a| a == flag) {
+        Some(pos) => {
+            args.remove(pos);
+            true
+        }
+        None => false,
+    }
+}
+
+fn parse_number(s: &str, what: &str) -> u32 {
+    match s.parse::<u32>() {
+        Ok(n) => n,
+        Err(_) => {
+            eprintln!("invalid {}: {}", what, s);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) {
+    let mut args = args.to_vec();
+    let big = take_flag(&mut args, "--big");
+    let options = input::take_path(&mut args).and_then(|path| {
+        let format = report::take_format(&mut args)?;
+        Ok((path, format, params::take_params(&mut args)?))
+    });
+
+    let (path, format, assignments) = match options {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let day = match args.first() {
+        Some(day) => parse_number(day, "day"),
+        None => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    let d = match (days::find(day), big) {
+        (Some(_), true) => days::find_big(day).unwrap_or_else(|| {
+            eprintln!("day {} can't count with big integers", day);
+            process::exit(1);
+        }),
+        (Some(d), false) => d,
+        (None, _) => {
+            eprintln!("day {} is not implemented", day);
+            process::exit(1);
+        }
+    };
+
+    let params = Params::parse(&assignments, d.params).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let parts = match args.get(1) {
+        Some(part) => vec![parse_number(part, "part")],
+        None => vec![1, 2],
+    };
+
+    if let Some(part) = parts.iter().find(|&&p| p != 1 && p != 2) {
+        eprintln!("invalid part: {}", part);
+        process::exit(1);
+    }
+
+    let data = match path {
+        Some(path) => input::load(&path),
+        None => input::load_data(&d.data_path()),
+    };
+
+    let solved = data.parse(|s| (d.solve)(s, &parts, &params));
+
+    if !report::print(format, day, &data.path, solved) {
+        process::exit(1);
+    }
+}
+
+fn list() {
+    for day in 1..=25 {
+        match days::find(day) {
+            Some(d) => {
+                let params: Vec<String> = d.params.iter().map(|p| p.to_string()).collect();
+                let line = format!("{:2}  implemented  {}", day, params.join(" "));
+                println!("{}", line.trim_end());
+            }
+            None => println!("{:2}  unimplemented", day),
+        }
+    }
+}
+
+/// Solves both parts of a day on its puzzle input, or returns `None` if the
+/// day has no puzzle input yet. Input that can't be read or parsed is
+/// returned as a message for the report, parts without an answer carry
+/// their own error.
+fn solve_data(d: &Day) -> Result<Option<Vec<Solved>>, String> {
+    let path = d.data_path();
+    let text = match input::read_data(&path) {
+        Ok(Some(text)) => text,
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(format!("could not read {}: {}", path, e)),
+    };
+
+    (d.solve)(&text, &[1, 2], &Params::default())
+        .map(Some)
+        .map_err(|e| e.with_file(&path).to_string())
+}
+
+fn all(args: &[String]) {
+    let mut args = args.to_vec();
+    let options = input::take_option(&mut args, &["--jobs", "-j"])
+        .and_then(|jobs| Ok((jobs, report::take_format(&mut args)?)));
+
+    let (jobs, format) = match options {
+        Ok((jobs, format)) if args.is_empty() => (
+            jobs.map_or_else(pool::threads, |n| {
+                parse_number(&n, "number of jobs") as usize
+            }),
+            format,
+        ),
+        Ok(_) => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let start = Instant::now();
+    let results = pool::map(days::DAYS, jobs, |d| {
+        let start = Instant::now();
+        (solve_data(d), start.elapsed())
+    });
+    let wall = start.elapsed();
+
+    let results: Vec<_> = results
+        .into_iter()
+        .map(|r| match r {
+            Ok((solved, elapsed)) => solved.map(|s| (s, elapsed)),
+            Err(panic) => Err(format!("panicked: {}", panic)),
+        })
+        .collect();
+
+    let mut total = Duration::ZERO;
+    let failed = results
+        .iter()
+        .filter(|r| match r {
+            Ok((Some(solved), _)) => solved.iter().any(|s| s.answer.is_err()),
+            Ok((None, _)) => false,
+            Err(_) => true,
+        })
+        .count();
+    let unsolved = results
+        .iter()
+        .filter(|r| matches!(r, Ok((None, _))))
+        .count();
+
+    // days without puzzle input have no records
+    if format == Format::Json {
+        let mut records = Vec::new();
+
+        for (d, result) in days::DAYS.iter().zip(results) {
+            let outcomes = match result {
+                Ok((solved, _)) => solved.into_iter().flatten().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            };
+
+            records.extend(outcomes.into_iter().map(|outcome| Record {
+                day: d.day,
+                input: d.data_path(),
+                outcome,
+            }));
+        }
+
+        print!("{}", report::json(&records));
+        if failed > 0 {
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    println!(
+        "{:>3}  {:>20}  {:>20}  {:>10}",
+        "day", "part1", "part2", "time"
+    );
+
+    for (d, result) in days::DAYS.iter().zip(&results) {
+        match result {
+            Ok((Some(solved), elapsed)) => {
+                total += *elapsed;
+                let cell = |s: &Solved| s.answer.clone().unwrap_or_else(|_| String::from("FAIL"));
+                println!(
+                    "{:>3}  {:>20}  {:>20}  {:>8.1}ms",
+                    d.day,
+                    cell(&solved[0]),
+                    cell(&solved[1]),
+                    ms(*elapsed)
+                );
+
+                for s in solved {
+                    if let Err(e) = &s.answer {
+                        println!("{:>3}  FAIL  part {}: {}", d.day, s.part, e);
+                    }
+                }
+            }
+            Ok((None, _)) => println!("{:>3}  no input", d.day),
+            Err(e) => println!("{:>3}  FAIL  {}", d.day, e),
+        }
+    }
+
+    println!(
+        "total: {:.1}ms  wall: {:.1}ms  jobs: {}  failed: {}  no input: {}",
+        ms(total),
+        ms(wall),
+        jobs,
+        failed,
+        unsolved
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// The inputs to verify a day against: the ones in the manifest and the
+/// sample and puzzle input if they exist.
+fn verify_inputs(d: &Day, answers: &[Answer]) -> Vec<String> {
+    let mut inputs: Vec<String> = Vec::new();
+
+    let recorded = answers
+        .iter()
+        .filter(|a| a.day == d.day)
+        .map(|a| a.input.clone());
+    let files = [d.test_path(), d.data_path()]
+        .into_iter()
+        .filter(|p| Path::new(p).exists());
+
+    for input in recorded.chain(files) {
+        if !inputs.contains(&input) {
+            inputs.push(input);
+        }
+    }
+
+    inputs
+}
+
+fn verify(args: &[String]) {
+    let path = args.first().map_or(answers::MANIFEST, |s| s.as_str());
+    let answers = input::load(path).parse(answers::parse);
+
+    let mut failed = 0;
+    let mut missing = 0;
+
+    for d in days::DAYS {
+        for input in verify_inputs(d, &answers) {
+            let solved =
+                input::read(&input).map(|text| (d.solve)(&text, &[1, 2], &Params::default()));
+
+            for part in [1, 2] {
+                let result = match (answers::find(&answers, d.day, part, &input), &solved) {
+                    (None, _) => {
+                        missing += 1;
+                        String::from("missing")
+                    }
+                    (Some(_), Err(e)) => {
+                        failed += 1;
+                        format!("FAIL  could not read input: {}", e)
+                    }
+                    (Some(a), Ok(solved)) => match solved {
+                        Ok(solved) => match &solved[part as usize - 1].answer {
+                            Ok(answer) if *answer == a.expected => String::from("pass"),
+                            Ok(answer) => {
+                                failed += 1;
+                                format!("FAIL  expected {}, got {}", a.expected, answer)
+                            }
+                            Err(e) => {
+                                failed += 1;
+                                format!("FAIL  {}", e)
+                            }
+                        },
+                        Err(e) => {
+                            failed += 1;
+                            format!("FAIL  {}", e.clone().with_file(&input))
+                        }
+                    },
+                };
+
+                println!("{:>3}  {}  {:<18}  {}", d.day, part, input, result);
+            }
+        }
+    }
+
+    for a in answers.iter().filter(|a| days::find(a.day).is_none()) {
+        failed += 1;
+        println!(
+            "{:>3}  {}  {:<18}  FAIL  day is not implemented",
+            a.day, a.part, a.input
+        );
+    }
+
+    println!("{} failed, {} missing", failed, missing);
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn print_stats(day: u32, phase: &str, stats: &Stats) {
+    println!(
+        "{:>3}  {:<5}  {:>10.3}ms  {:>10.3}ms  {:>10.3}ms",
+        day,
+        phase,
+        ms(stats.min),
+        ms(stats.median),
+        ms(stats.max)
+    );
+}
+
+fn bench(args: &[String]) {
+    let mut args = args.to_vec();
+    let options = input::take_option(&mut args, &["--runs", "-n"])
+        .and_then(|runs| Ok((runs, input::take_option(&mut args, &["--json"])?)));
+
+    let (runs, json) = match options {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let runs = runs.map_or(10, |n| parse_number(&n, "number of runs")) as usize;
+    if runs == 0 {
+        eprintln!("invalid number of runs: 0");
+        process::exit(1);
+    }
+
+    let selected: Vec<&Day> = if args.is_empty() {
+        days::DAYS.iter().collect()
+    } else {
+        args.iter()
+            .map(|a| {
+                let day = parse_number(a, "day");
+                days::find(day).unwrap_or_else(|| {
+                    eprintln!("day {} is not implemented", day);
+                    process::exit(1);
+                })
+            })
+            .collect()
+    };
+
+    let mut results: Vec<(u32, String, Timings)> = Vec::new();
+
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+        "day", "phase", "min", "median", "max"
+    );
+
+    for d in selected {
+        let data = match input::read_data(&d.data_path()) {
+            Ok(Some(text)) => input::Input {
+                path: d.data_path(),
+                text,
+            },
+            Ok(None) => {
+                println!("{:>3}  no input", d.day);
+                continue;
+            }
+            Err(e) => {
+                eprintln!("could not read {}: {}", d.data_path(), e);
+                process::exit(1);
+            }
+        };
+        let timings = data.parse(|s| (d.bench)(s, runs));
+
+        print_stats(d.day, "parse", &timings.parse);
+        print_stats(d.day, "part1", &timings.part1);
+        print_stats(d.day, "part2", &timings.part2);
+
+        results.push((d.day, d.data_path(), timings));
+    }
+
+    if let Some(path) = json {
+        let report = bench::json_report(runs, &results);
+
+        if path == "-" {
+            print!("{}", report);
+        } else if let Err(e) = std::fs::write(&path, report) {
+            eprintln!("could not write {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn generate(args: &[String]) {
+    let mut args = args.to_vec();
+    let options = input::take_option(&mut args, &["--seed", "-s"]).and_then(|seed| {
+        let size = input::take_option(&mut args, &["--size"])?;
+        Ok((
+            seed,
+            size,
+            input::take_option(&mut args, &["--output", "-o"])?,
+        ))
+    });
+
+    let (seed, size, output) = match options {
+        Ok(options) if args.len() == 1 => options,
+        Ok(_) => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let day = parse_number(&args[0], "day");
+    let d = days::find(day).unwrap_or_else(|| {
+        eprintln!("day {} is not implemented", day);
+        process::exit(1);
+    });
+
+    let seed = seed.map_or(0, |s| parse_number(&s, "seed"));
+    let size = size.map_or(100, |s| parse_number(&s, "size"));
+    let text = (d.generate)(&mut Rng::new(seed.into()), size as usize);
+
+    match output {
+        Some(path) if path != "-" => {
+            if let Err(e) = std::fs::write(&path, text) {
+                eprintln!("could not write {}: {}", path, e);
+                process::exit(1);
+            }
+        }
+        _ => print!("{}", text),
+    }
+}
+
+fn animate(args: &[String]) {
+    let mut args = args.to_vec();
+    let paused = take_flag(&mut args, "--paused");
+    let options = input::take_path(&mut args).and_then(|path| {
+        let fps = input::take_option(&mut args, &["--fps"])?;
+        Ok((path, fps, params::take_params(&mut args)?))
+    });
+
+    let (path, fps, assignments) = match options {
+        Ok(options) if args.len() == 1 => options,
+        Ok(_) => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let day = parse_number(&args[0], "day");
+    let d = days::find(day).unwrap_or_else(|| {
+        eprintln!("day {} is not implemented", day);
+        process::exit(1);
+    });
+
+    let params = Params::parse(&assignments, d.params).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let fps = match fps.map(|n| n.parse::<f64>()) {
+        None => Playback::default().fps,
+        Some(Ok(fps)) if fps > 0.0 => fps,
+        Some(_) => {
+            eprintln!("invalid frames per second");
+            process::exit(1);
+        }
+    };
+
+    // keys can't be read from stdin when it holds the input
+    let keyboard = path.as_deref() != Some("-");
+    let data = match path {
+        Some(path) => input::load(&path),
+        None => input::load_data(&d.data_path()),
+    };
+
+    let animated = data.parse(|s| {
+        (d.animate)(s, &params, &mut |frames| {
+            let raw = if keyboard {
+                animate::RawMode::enable()
+            } else {
+                None
+            };
+            let controls = if raw.is_some() {
+                animate::keyboard()
+            } else {
+                std::sync::mpsc::channel().1
+            };
+
+            let playback = Playback { fps, paused };
+            if let Err(e) = animate::play(frames, &mut std::io::stdout(), &controls, playback) {
+                drop(raw);
+                eprintln!("could not draw the animation: {}", e);
+                process::exit(1);
+            }
+        })
+    });
+
+    if !animated {
+        eprintln!("day {} has no animation", day);
+        process::exit(1);
+    }
+}
+
+fn draw(args: &[String]) {
+    let mut args = args.to_vec();
+    let options = input::take_path(&mut args).and_then(|path| {
+        let output = input::take_option(&mut args, &["--output", "-o"])?;
+        let scale = input::take_option(&mut args, &["--scale"])?;
+        Ok((path, output, scale, params::take_params(&mut args)?))
+    });
+
+    let (path, output, scale, assignments) = match options {
+        Ok((path, Some(output), scale, assignments)) if args.len() == 1 => {
+            (path, output, scale, assignments)
+        }
+        Ok(_) => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let encoding = Encoding::from_path(&output).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let day = parse_number(&args[0], "day");
+    let d = days::find(day).unwrap_or_else(|| {
+        eprintln!("day {} is not implemented", day);
+        process::exit(1);
+    });
+
+    let params = Params::parse(&assignments, d.params).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let scale = scale.map_or(1, |n| parse_number(&n, "scale")) as usize;
+    if scale == 0 {
+        eprintln!("invalid scale: 0");
+        process::exit(1);
+    }
+
+    let data = match path {
+        Some(path) => input::load(&path),
+        None => input::load_data(&d.data_path()),
+    };
+    let picture = match data.parse(|s| (d.image)(s, &params)) {
+        Some(picture) => image::scale(&picture, scale),
+        None => {
+            eprintln!("day {} has no image", day);
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = std::fs::write(&output, image::encode(&picture, encoding)) {
+        eprintln!("could not write {}: {}", output, e);
+        process::exit(1);
+    }
+}
+
+fn record(args: &[String]) {
+    let mut args = args.to_vec();
+    let options = input::take_path(&mut args).and_then(|path| {
+        let output = input::take_option(&mut args, &["--output", "-o"])?;
+        let scale = input::take_option(&mut args, &["--scale"])?;
+        let delay = input::take_option(&mut args, &["--delay"])?;
+        Ok((path, output, scale, delay, params::take_params(&mut args)?))
+    });
+
+    let (path, output, scale, delay, assignments) = match options {
+        Ok((path, Some(output), scale, delay, assignments)) if args.len() == 1 => {
+            (path, output, scale, delay, assignments)
+        }
+        Ok(_) => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let day = parse_number(&args[0], "day");
+    let d = days::find(day).unwrap_or_else(|| {
+        eprintln!("day {} is not implemented", day);
+        process::exit(1);
+    });
+
+    let params = Params::parse(&assignments, d.params).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let scale = scale.map_or(4, |n| parse_number(&n, "scale")) as usize;
+    if scale == 0 {
+        eprintln!("invalid scale: 0");
+        process::exit(1);
+    }
+
+    let delay = delay.map_or(10, |n| parse_number(&n, "delay"));
+    let delay = u16::try_from(delay).unwrap_or_else(|_| {
+        eprintln!("invalid delay: {}", delay);
+        process::exit(1);
+    });
+
+    let data = match path {
+        Some(path) => input::load(&path),
+        None => input::load_data(&d.data_path()),
+    };
+    let movie = match data.parse(|s| (d.movie)(s, &params)) {
+        Some(movie) => movie,
+        None => {
+            eprintln!("day {} has no animation", day);
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = std::fs::write(&output, movie.encode(delay, scale)) {
+        eprintln!("could not write {}: {}", output, e);
+        process::exit(1);
+    }
+}
+
+/// Adds a day to the repository in the current directory.
+fn new(args: &[String]) {
+    let (day, title) = match args.split_first() {
+        Some((day, title)) => (parse_number(day, "day"), title.join(" ")),
+        None => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    match scaffold::create(Path::new("."), day, &title) {
+        Ok(paths) => {
+            for p in paths {
+                println!("wrote {}", p);
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("list") => list(),
+        Some("all") => all(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("animate") => animate(&args[1..]),
+        Some("image") => draw(&args[1..]),
+        Some("gif") => record(&args[1..]),
+        Some("new") => new(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
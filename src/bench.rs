@@ -0,0 +1,109 @@
+//! Timing of the parse, part 1 and part 2 phases of a day.
+
+use crate::parse::ParseError;
+use crate::report;
+use std::hint::black_box;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Summary of the timings of one phase over all runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut times: Vec<Duration>) -> Self {
+        times.sort_unstable();
+
+        Self {
+            min: times[0],
+            median: times[times.len() / 2],
+            max: times[times.len() - 1],
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            "{{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+/// Timings of all phases of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Formats the timings of several days as a JSON report. Every result is
+/// the day, the path of the input and the timings.
+pub fn json_report(runs: usize, results: &[(u32, String, Timings)]) -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let days: Vec<String> = results
+        .iter()
+        .map(|(day, input, t)| {
+            format!(
+                "    {{\"day\": {}, \"input\": {}, \"parse\": {}, \"part1\": {}, \"part2\": {}}}",
+                day,
+                report::json_string(input),
+                t.parse.to_json(),
+                t.part1.to_json(),
+                t.part2.to_json()
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"timestamp\": {},\n  \"runs\": {},\n  \"days\": [\n{}\n  ]\n}}\n",
+        timestamp,
+        runs,
+        days.join(",\n")
+    )
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let r = black_box(f());
+    (r, start.elapsed())
+}
+
+/// Runs every phase `runs` times, which has to be at least one. Every run
+/// parses the input again and solves both parts on its result.
+pub fn measure<I, A, B>(
+    s: &str,
+    runs: usize,
+    parse: impl Fn(&str) -> Result<I, ParseError>,
+    part1: impl Fn(&I) -> A,
+    part2: impl Fn(&I) -> B,
+) -> Result<Timings, ParseError> {
+    assert!(runs > 0);
+
+    let mut parse_times = Vec::new();
+    let mut part1_times = Vec::new();
+    let mut part2_times = Vec::new();
+
+    for _ in 0..runs {
+        let (input, t) = time(|| parse(black_box(s)));
+        let input = input?;
+        parse_times.push(t);
+
+        part1_times.push(time(|| part1(&input)).1);
+        part2_times.push(time(|| part2(&input)).1);
+    }
+
+    Ok(Timings {
+        parse: Stats::new(parse_times),
+        part1: Stats::new(part1_times),
+        part2: Stats::new(part2_times),
+    })
+}
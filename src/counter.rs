@@ -0,0 +1,233 @@
+//! Number types for counting things that grow exponentially, such as fish or
+//! universes. All arithmetic is checked, so an overflow is reported instead
+//! of giving a wrong count.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+
+/// A non-negative number type to count with.
+pub trait Counter: Clone + Ord + fmt::Debug + fmt::Display {
+    /// The name of the type for error messages.
+    const NAME: &'static str;
+
+    fn from_u64(n: u64) -> Self;
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    fn zero() -> Self {
+        Self::from_u64(0)
+    }
+}
+
+macro_rules! primitive_counter {
+    ($($t:ty),*) => {
+        $(
+            impl Counter for $t {
+                const NAME: &'static str = stringify!($t);
+
+                fn from_u64(n: u64) -> Self {
+                    n.try_into().expect("the counter holds every u64")
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+primitive_counter!(u64, u128);
+
+/// An unsigned integer of arbitrary size, which never overflows.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without leading zeros.
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    fn trim(mut self) -> Self {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+
+        self
+    }
+
+    /// Divides by a small number in place and returns the remainder.
+    fn div_rem_small(&mut self, d: u32) -> u32 {
+        let mut rem = 0u64;
+
+        for digit in self.digits.iter_mut().rev() {
+            let n = (rem << 32) | *digit as u64;
+            *digit = (n / d as u64) as u32;
+            rem = n % d as u64;
+        }
+
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+
+        rem as u32
+    }
+}
+
+impl Counter for BigUint {
+    const NAME: &'static str = "BigUint";
+
+    fn from_u64(n: u64) -> Self {
+        Self {
+            digits: vec![n as u32, (n >> 32) as u32],
+        }
+        .trim()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        let len = self.digits.len().max(other.digits.len());
+        let mut digits = Vec::with_capacity(len + 1);
+        let mut carry = 0u64;
+
+        for i in 0..len {
+            let a = *self.digits.get(i).unwrap_or(&0) as u64;
+            let b = *other.digits.get(i).unwrap_or(&0) as u64;
+            let sum = a + b + carry;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+        digits.push(carry as u32);
+
+        Some(Self { digits }.trim())
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self < other {
+            return None;
+        }
+
+        let mut digits = Vec::with_capacity(self.digits.len());
+        let mut borrow = 0i64;
+
+        for (i, &a) in self.digits.iter().enumerate() {
+            let b = *other.digits.get(i).unwrap_or(&0) as i64;
+            let mut d = a as i64 - b - borrow;
+            borrow = 0;
+            if d < 0 {
+                d += 1 << 32;
+                borrow = 1;
+            }
+            digits.push(d as u32);
+        }
+
+        Some(Self { digits }.trim())
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let mut digits = vec![0u32; self.digits.len() + other.digits.len()];
+
+        for (i, &a) in self.digits.iter().enumerate() {
+            let mut carry = 0u64;
+
+            for (j, &b) in other.digits.iter().enumerate() {
+                let n = a as u64 * b as u64 + digits[i + j] as u64 + carry;
+                digits[i + j] = n as u32;
+                carry = n >> 32;
+            }
+            digits[i + other.digits.len()] = carry as u32;
+        }
+
+        Some(Self { digits }.trim())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // nine decimal digits at a time, least significant first
+        let mut n = self.clone();
+        let mut chunks = Vec::new();
+
+        while !n.digits.is_empty() {
+            chunks.push(n.div_rem_small(1_000_000_000));
+        }
+
+        match chunks.pop() {
+            None => write!(f, "0"),
+            Some(first) => {
+                write!(f, "{}", first)?;
+                for c in chunks.iter().rev() {
+                    write!(f, "{:09}", c)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// A count got too large for its type in a step of a simulation, such as a
+/// day of day 6 or a turn of day 21.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub counter: &'static str,
+    pub step: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the counts overflow {} in step {}",
+            self.counter, self.step
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+/// Checked arithmetic that reports the step an overflow happened in.
+pub fn add<C: Counter>(a: &C, b: &C, step: usize) -> Result<C, Overflow> {
+    a.checked_add(b).ok_or(Overflow {
+        counter: C::NAME,
+        step,
+    })
+}
+
+pub fn mul<C: Counter>(a: &C, b: &C, step: usize) -> Result<C, Overflow> {
+    a.checked_mul(b).ok_or(Overflow {
+        counter: C::NAME,
+        step,
+    })
+}
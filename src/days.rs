@@ -0,0 +1,151 @@
+//! Registry of all implemented days so they can be run by number.
+
+use crate::animate::Frames;
+use crate::bench::{self, Timings};
+use crate::counter::BigUint;
+use crate::gif::Movie;
+use crate::image::Image;
+use crate::params::{Param, Params};
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::solution::{self, Solution, Solved};
+use crate::*;
+
+/// Parses the puzzle input once and solves the given parts, which have to be
+/// 1 or 2, in order.
+pub type Solver = fn(&str, &[u32], &Params) -> Result<Vec<Solved>, ParseError>;
+
+/// Generates a random puzzle input from a random number generator and a
+/// size.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// Times the phases of a day on the puzzle input over the given number of
+/// runs.
+pub type Bench = fn(&str, usize) -> Result<Timings, ParseError>;
+
+/// Parses the puzzle input and passes the frames of its simulation to the
+/// player. Returns false if the day has no animation.
+pub type Animator = fn(&str, &Params, &mut dyn FnMut(Frames)) -> Result<bool, ParseError>;
+
+/// Parses the puzzle input and draws a picture of it. Returns `None` if the
+/// day has no picture.
+pub type Painter = fn(&str, &Params) -> Result<Option<Image>, ParseError>;
+
+/// Parses the puzzle input and records its simulation. Returns `None` if the
+/// day has no animation.
+pub type Recorder = fn(&str, &Params) -> Result<Option<Movie>, ParseError>;
+
+/// An implemented day.
+pub struct Day {
+    pub day: u32,
+    pub params: &'static [Param],
+    pub solve: Solver,
+    pub bench: Bench,
+    pub generate: Generator,
+    pub animate: Animator,
+    pub image: Painter,
+    pub movie: Recorder,
+}
+
+impl Day {
+    /// Path of the puzzle input, relative to the repository root.
+    pub fn data_path(&self) -> String {
+        format!("src/{}/data.txt", self.day)
+    }
+
+    /// Path of the example input from the puzzle text.
+    pub fn test_path(&self) -> String {
+        format!("src/{}/test.txt", self.day)
+    }
+}
+
+fn bench<S: Solution>(s: &str, runs: usize) -> Result<Timings, ParseError> {
+    let params = Params::default();
+    bench::measure(
+        s,
+        runs,
+        S::parse,
+        |i| S::part1(i, &params),
+        |i| S::part2(i, &params),
+    )
+}
+
+fn animate<S: Solution>(
+    s: &str,
+    params: &Params,
+    play: &mut dyn FnMut(Frames),
+) -> Result<bool, ParseError> {
+    let input = S::parse(s)?;
+    let frames = S::frames(&input, params);
+    let animated = frames.is_some();
+
+    if let Some(frames) = frames {
+        play(frames);
+    }
+
+    Ok(animated)
+}
+
+fn image<S: Solution>(s: &str, params: &Params) -> Result<Option<Image>, ParseError> {
+    Ok(S::image(&S::parse(s)?, params))
+}
+
+fn movie<S: Solution>(s: &str, params: &Params) -> Result<Option<Movie>, ParseError> {
+    Ok(S::movie(&S::parse(s)?, params))
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        day: S::DAY,
+        params: S::PARAMS,
+        solve: solution::solve::<S>,
+        bench: bench::<S>,
+        generate: S::generate,
+        animate: animate::<S>,
+        image: image::<S>,
+        movie: movie::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(),
+    day::<day2::Day2>(),
+    day::<day3::Day3>(),
+    day::<day4::Day4>(),
+    day::<day5::Day5>(),
+    day::<day6::Day6>(),
+    day::<day7::Day7>(),
+    day::<day8::Day8>(),
+    day::<day9::Day9>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+    day::<day15::Day15>(),
+    day::<day16::Day16>(),
+    day::<day17::Day17>(),
+    day::<day18::Day18>(),
+    day::<day19::Day19>(),
+    day::<day20::Day20>(),
+    day::<day21::Day21>(),
+    day::<day22::Day22>(),
+];
+
+/// The days whose counts grow exponentially, counting with integers of
+/// arbitrary size instead of `u64`.
+pub const BIG: &[Day] = &[
+    day::<day6::Day6<BigUint>>(),
+    day::<day14::Day14<BigUint>>(),
+    day::<day21::Day21<BigUint>>(),
+];
+
+/// Looks up an implemented day by its number.
+pub fn find(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Looks up a day that can count with integers of arbitrary size.
+pub fn find_big(day: u32) -> Option<&'static Day> {
+    BIG.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,175 @@
+//! Export of animations as GIF files with a hand-written LZW encoder.
+
+use crate::grid::Grid;
+use crate::image::{self, Rgb};
+use std::collections::HashMap;
+
+/// The largest code the LZW compression of GIF may use.
+const MAX_CODE: u16 = 4095;
+
+/// Packs codes of varying width into bytes, lowest bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.bits |= (code as u32) << self.len;
+        self.len += width;
+
+        while self.len >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+
+        self.bytes
+    }
+}
+
+/// Compresses palette indices, which have to be below `1 << min_code_size`,
+/// with the variable-width LZW of GIF.
+pub fn lzw(min_code_size: u8, indices: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let first_width = min_code_size as u32 + 1;
+
+    let mut out = BitWriter {
+        bytes: Vec::new(),
+        bits: 0,
+        len: 0,
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut width = first_width;
+    let mut next = end + 1;
+
+    out.write(clear, width);
+
+    let mut indices = indices.iter();
+    let Some(&first) = indices.next() else {
+        out.write(end, width);
+        return out.finish();
+    };
+    let mut prefix = first as u16;
+
+    for &i in indices {
+        if let Some(&code) = table.get(&(prefix, i)) {
+            prefix = code;
+            continue;
+        }
+
+        out.write(prefix, width);
+        table.insert((prefix, i), next);
+        next += 1;
+
+        // the decoder adds its entries one code later, so the width grows
+        // once the next code doesn't fit anymore
+        if next > 1 << width {
+            width += 1;
+        }
+
+        if next > MAX_CODE {
+            out.write(clear, width);
+            table.clear();
+            width = first_width;
+            next = end + 1;
+        }
+
+        prefix = i as u16;
+    }
+
+    out.write(prefix, width);
+
+    // the decoder counts an entry for the last code too
+    if next + 1 > 1 << width {
+        width += 1;
+    }
+    out.write(end, width);
+
+    out.finish()
+}
+
+/// Splits data into the length-prefixed sub-blocks of GIF.
+fn sub_blocks(out: &mut Vec<u8>, data: &[u8]) {
+    for block in data.chunks(255) {
+        out.push(block.len() as u8);
+        out.extend_from_slice(block);
+    }
+
+    out.push(0);
+}
+
+/// An animation as frames of palette indices, which all have the same size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Movie {
+    /// Up to 256 colours.
+    pub palette: Vec<Rgb>,
+    pub frames: Vec<Grid<u8>>,
+}
+
+impl Movie {
+    /// Encodes the movie as a looping GIF that shows every frame for `delay`
+    /// hundredths of a second, with every cell as `scale` by `scale` pixels.
+    pub fn encode(&self, delay: u16, scale: usize) -> Vec<u8> {
+        assert!(!self.palette.is_empty() && self.palette.len() <= 256);
+
+        let (width, height) = self
+            .frames
+            .first()
+            .map_or((0, 0), |f| (f.width() * scale, f.height() * scale));
+        assert!(width <= u16::MAX as usize && height <= u16::MAX as usize);
+
+        // the colour table has 2, 4, ... or 256 entries
+        let bits = (self.palette.len().next_power_of_two().trailing_zeros()).max(1);
+        let min_code_size = bits.max(2) as u8;
+
+        let mut out = b"GIF89a".to_vec();
+        out.extend_from_slice(&(width as u16).to_le_bytes());
+        out.extend_from_slice(&(height as u16).to_le_bytes());
+        // global colour table with 8 bits per channel, background colour,
+        // square pixels
+        out.extend_from_slice(&[0xf0 | (bits - 1) as u8, 0, 0]);
+        for i in 0..1 << bits {
+            out.extend_from_slice(self.palette.get(i).unwrap_or(&image::BLACK));
+        }
+
+        // loop forever
+        out.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\0\0\0");
+
+        for frame in &self.frames {
+            assert_eq!(
+                (frame.width() * scale, frame.height() * scale),
+                (width, height)
+            );
+
+            // graphic control extension with the delay and no transparency
+            out.extend_from_slice(&[0x21, 0xf9, 4, 0]);
+            out.extend_from_slice(&delay.to_le_bytes());
+            out.extend_from_slice(&[0, 0]);
+
+            // image descriptor covering the whole screen
+            out.push(0x2c);
+            out.extend_from_slice(&[0, 0, 0, 0]);
+            out.extend_from_slice(&(width as u16).to_le_bytes());
+            out.extend_from_slice(&(height as u16).to_le_bytes());
+            out.push(0);
+
+            let frame = image::scale(frame, scale);
+            let indices: Vec<u8> = frame.iter().copied().collect();
+
+            out.push(min_code_size);
+            sub_blocks(&mut out, &lzw(min_code_size, &indices));
+        }
+
+        out.push(0x3b);
+        out
+    }
+}
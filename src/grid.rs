@@ -0,0 +1,238 @@
+//! A rectangular grid of cells addressed by `(x, y)` coordinates, with `(0, 0)`
+//! in the top left corner.
+
+use crate::parse::{ParseError, Scanner};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+const OFFSETS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height);
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, v: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![v; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, p: (usize, usize)) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.1 * self.width + p.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: (usize, usize)) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.1 * self.width + p.0])
+        } else {
+            None
+        }
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let p = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            if self.contains(p) {
+                Some(p)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The horizontal and vertical neighbors of a cell inside the grid.
+    pub fn neighbors4(&self, p: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(p, &OFFSETS4)
+    }
+
+    /// The horizontal, vertical and diagonal neighbors of a cell inside the
+    /// grid.
+    pub fn neighbors8(&self, p: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(p, &OFFSETS8)
+    }
+
+    /// All coordinates in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Formats the grid with one character per cell.
+    pub fn display_with(&self, f: impl Fn(&T) -> char) -> String {
+        let mut s = String::new();
+
+        for row in self.rows() {
+            s.extend(row.iter().map(&f));
+            s.push('\n');
+        }
+
+        s
+    }
+}
+
+/// Parses a rectangle of cells with one character each. `cell` returns `None`
+/// for characters that aren't cells.
+pub fn parse_cells<T>(
+    s: &str,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut cells = Vec::new();
+    let mut width = None;
+    let mut height = 0;
+
+    for mut l in Scanner::lines(s) {
+        let row = l.take_while(|c| cell(c).is_some());
+        let len = row.chars().count();
+
+        match width {
+            None if row.is_empty() => return Err(l.error(expected)),
+            Some(w) if len != w => {
+                let pos = row.char_indices().nth(w).map_or(row.len(), |(i, _)| i);
+                return Err(l.error_at(pos, format!("{} cells", w)));
+            }
+            _ => l.end()?,
+        }
+
+        width = Some(len);
+        height += 1;
+        cells.extend(row.chars().filter_map(&cell));
+    }
+
+    match width {
+        Some(w) => Ok(Grid::new(w, height, cells)),
+        None => Err(ParseError::end_of_input(height, expected)),
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a rectangle of decimal digits.
+    pub fn parse_digits(s: &str) -> Result<Self, ParseError> {
+        parse_cells(s, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl Grid<bool> {
+    /// Parses a rectangle of set (`#`) and unset (`.`) cells.
+    pub fn parse_pixels(s: &str) -> Result<Self, ParseError> {
+        parse_cells(s, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: (usize, usize)) -> &T {
+        self.get(p).expect("position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, p: (usize, usize)) -> &mut T {
+        self.get_mut(p).expect("position outside of the grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "[{} x {}]", self.width, self.height)?;
+        for row in self.rows() {
+            writeln!(f, "{:?}", row)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+//! Export of grids as PPM or PNG images, written without any libraries.
+//!
+//! The PNG encoder stores the pixels uncompressed inside the zlib stream,
+//! which makes the files about as large as PPM but readable by every image
+//! viewer.
+
+use crate::grid::Grid;
+use std::path::Path;
+
+/// A colour with red, green and blue components.
+pub type Rgb = [u8; 3];
+
+/// An image with one colour per cell.
+pub type Image = Grid<Rgb>;
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const RED: Rgb = [230, 30, 30];
+
+fn channel(t: f64) -> u8 {
+    (t.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// A shade of gray from black for 0 to white for `max`.
+pub fn gray(v: u32, max: u32) -> Rgb {
+    let c = channel(v as f64 / max.max(1) as f64);
+    [c, c, c]
+}
+
+/// A colour from black for 0 over red and yellow to white for `max`.
+pub fn heat(v: u32, max: u32) -> Rgb {
+    let t = 3.0 * v as f64 / max.max(1) as f64;
+    [channel(t), channel(t - 1.0), channel(t - 2.0)]
+}
+
+/// A bright colour for an id. Consecutive ids get clearly different hues.
+pub fn palette(id: u32) -> Rgb {
+    // stepping by the golden ratio spreads the hues evenly
+    let hue = (id as f64 * 0.618_033_988_75).fract() * 6.0;
+    let (s, v) = (0.65, 0.95);
+
+    let f = hue.fract();
+    let (p, q, t) = (v * (1.0 - s), v * (1.0 - s * f), v * (1.0 - s * (1.0 - f)));
+    let (r, g, b) = match hue as u32 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    [channel(r), channel(g), channel(b)]
+}
+
+/// Enlarges every pixel to a `factor` by `factor` square.
+pub fn scale<T: Copy>(image: &Grid<T>, factor: usize) -> Grid<T> {
+    let (width, height) = (image.width() * factor, image.height() * factor);
+    let cells = (0..width * height)
+        .map(|i| image[(i % width / factor, i / width / factor)])
+        .collect();
+
+    Grid::new(width, height, cells)
+}
+
+/// The file formats an image can be written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Ppm,
+    Png,
+}
+
+impl Encoding {
+    /// Picks the format from the extension of a file name.
+    pub fn from_path(path: &str) -> Result<Self, String> {
+        match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some(e) if e.eq_ignore_ascii_case("ppm") => Ok(Self::Ppm),
+            Some(e) if e.eq_ignore_ascii_case("png") => Ok(Self::Png),
+            _ => Err(format!(
+                "unknown image format of {}, use .ppm or .png",
+                path
+            )),
+        }
+    }
+}
+
+pub fn encode(image: &Image, encoding: Encoding) -> Vec<u8> {
+    match encoding {
+        Encoding::Ppm => ppm(image),
+        Encoding::Png => png(image),
+    }
+}
+
+/// Encodes an image as binary PPM.
+pub fn ppm(image: &Image) -> Vec<u8> {
+    let mut data = format!("P6\n{} {}\n255\n", image.width(), image.height()).into_bytes();
+
+    for rgb in image.iter() {
+        data.extend_from_slice(rgb);
+    }
+
+    data
+}
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+
+    table
+}
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+/// The CRC-32 checksum PNG uses for its chunks.
+pub fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |c, &b| {
+        CRC_TABLE[((c ^ b as u32) & 0xff) as usize] ^ (c >> 8)
+    })
+}
+
+/// The Adler-32 checksum at the end of a zlib stream.
+pub fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &x| {
+        let a = (a + x as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+/// Wraps data in a zlib stream of uncompressed deflate blocks.
+pub fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate, 32K window, no dictionary, fastest compression
+    let mut out = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Encodes an image as an 8-bit RGB PNG.
+pub fn png(image: &Image) -> Vec<u8> {
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&(image.width() as u32).to_be_bytes());
+    header.extend_from_slice(&(image.height() as u32).to_be_bytes());
+    // bit depth, colour type RGB, compression, filter, no interlacing
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    chunk(&mut out, b"IHDR", &header);
+
+    let mut raw = Vec::with_capacity(image.height() * (image.width() * 3 + 1));
+    for row in image.rows() {
+        // every scanline starts with its filter type, none here
+        raw.push(0);
+        for rgb in row {
+            raw.extend_from_slice(rgb);
+        }
+    }
+    chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    chunk(&mut out, b"IEND", &[]);
+
+    out
+}
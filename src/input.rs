@@ -0,0 +1,119 @@
+//! Reading puzzle input from a file or from stdin.
+
+use crate::parse::ParseError;
+use std::io::{self, Read};
+use std::process;
+
+/// Puzzle input and the path it was read from.
+pub struct Input {
+    pub path: String,
+    pub text: String,
+}
+
+impl Input {
+    /// Parses the input or exits with a diagnostic that points at the error.
+    pub fn parse<T>(&self, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> T {
+        match parse(&self.text) {
+            Ok(v) => v,
+            Err(e) => {
+                let file = if self.path == "-" {
+                    "<stdin>"
+                } else {
+                    &self.path
+                };
+                eprintln!("{}", diagnostic(&self.text, &e.with_file(file)));
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Formats a parse error together with the offending line of `text`.
+pub fn diagnostic(text: &str, e: &ParseError) -> String {
+    let mut s = format!("error: {}", e);
+
+    if let Some(line) = e.line.checked_sub(1).and_then(|l| text.lines().nth(l)) {
+        s += &format!("\n    {}\n    {:>width$}", line, "^", width = e.column);
+    }
+
+    s
+}
+
+/// Reads the puzzle input from `path`, or from stdin if the path is `-`.
+pub fn read(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s)?;
+        Ok(s)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+/// Reads the puzzle input of a day from `path`. Returns `None` if there is
+/// none yet, because the file is missing or empty as `aoc new` leaves it.
+pub fn read_data(path: &str) -> io::Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(text) if text.trim().is_empty() => Ok(None),
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Removes an option with a value, such as `--runs 10`, from the arguments
+/// and returns the value if the option was given under any of its `names`.
+pub fn take_option(args: &mut Vec<String>, names: &[&str]) -> Result<Option<String>, String> {
+    let pos = match args.iter().position(|a| names.contains(&a.as_str())) {
+        Some(pos) => pos,
+        None => return Ok(None),
+    };
+
+    if pos + 1 >= args.len() {
+        return Err(format!("missing value after {}", args[pos]));
+    }
+
+    let value = args.remove(pos + 1);
+    args.remove(pos);
+
+    Ok(Some(value))
+}
+
+/// Removes `--input <path>` or `-i <path>` from the arguments and returns the
+/// path if it was given.
+pub fn take_path(args: &mut Vec<String>) -> Result<Option<String>, String> {
+    take_option(args, &["--input", "-i"])
+}
+
+/// Reads the puzzle input of a day from `path`, or exits with a message if
+/// it can't be read or there is none yet.
+pub fn load_data(path: &str) -> Input {
+    match read_data(path) {
+        Ok(Some(text)) => Input {
+            path: path.to_string(),
+            text,
+        },
+        Ok(None) => {
+            eprintln!("no puzzle input in {}", path);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("could not read {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Reads the puzzle input from `path` or exits with a message.
+pub fn load(path: &str) -> Input {
+    match read(path) {
+        Ok(text) => Input {
+            path: path.to_string(),
+            text,
+        },
+        Err(e) => {
+            eprintln!("could not read {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Solvers for Advent of Code 2021.
+//!
+//! Every day lives in its own module next to its puzzle input in `src/N/`
+//! and exposes a `parse` function for the input text plus `part1` and
+//! `part2` functions that return the answers. A `DayN` type implements
+//! [`solution::Solution`] on top of them, which is what the runner uses.
+
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod counter;
+pub mod days;
+pub mod gif;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod pool;
+pub mod report;
+pub mod rng;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod space;
+
+#[path = "1/mod.rs"]
+pub mod day1;
+#[path = "10/mod.rs"]
+pub mod day10;
+#[path = "11/mod.rs"]
+pub mod day11;
+#[path = "12/mod.rs"]
+pub mod day12;
+#[path = "13/mod.rs"]
+pub mod day13;
+#[path = "14/mod.rs"]
+pub mod day14;
+#[path = "15/mod.rs"]
+pub mod day15;
+#[path = "16/mod.rs"]
+pub mod day16;
+#[path = "17/mod.rs"]
+pub mod day17;
+#[path = "18/mod.rs"]
+pub mod day18;
+#[path = "19/mod.rs"]
+pub mod day19;
+#[path = "2/mod.rs"]
+pub mod day2;
+#[path = "20/mod.rs"]
+pub mod day20;
+#[path = "21/mod.rs"]
+pub mod day21;
+#[path = "22/mod.rs"]
+pub mod day22;
+#[path = "3/mod.rs"]
+pub mod day3;
+#[path = "4/mod.rs"]
+pub mod day4;
+#[path = "5/mod.rs"]
+pub mod day5;
+#[path = "6/mod.rs"]
+pub mod day6;
+#[path = "7/mod.rs"]
+pub mod day7;
+#[path = "8/mod.rs"]
+pub mod day8;
+#[path = "9/mod.rs"]
+pub mod day9;
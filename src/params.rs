@@ -0,0 +1,86 @@
+//! Named puzzle parameters, such as the number of steps to simulate, that
+//! can be changed from the command line with `--param name=value`.
+
+use std::fmt;
+
+/// A parameter of a day and its value in the puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    /// The smallest and largest values the day can solve with, which also
+    /// keep the value within the integer type the day uses for it.
+    pub min: u64,
+    pub max: u64,
+    pub help: &'static str,
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.default)
+    }
+}
+
+/// The parameters given on the command line. All others keep their default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, u64)>);
+
+impl Params {
+    /// Parses `name=value` assignments. Every name has to be one of `known`.
+    pub fn parse(assignments: &[String], known: &[Param]) -> Result<Self, String> {
+        let mut params = Vec::new();
+
+        for a in assignments {
+            let (name, value) = a
+                .split_once('=')
+                .ok_or_else(|| format!("expected name=value, found {}", a))?;
+
+            let Some(param) = known.iter().find(|p| p.name == name) else {
+                let names: Vec<&str> = known.iter().map(|p| p.name).collect();
+                return Err(if names.is_empty() {
+                    format!("unknown parameter {}, the day has no parameters", name)
+                } else {
+                    format!(
+                        "unknown parameter {}, expected one of: {}",
+                        name,
+                        names.join(", ")
+                    )
+                });
+            };
+
+            let value = value
+                .parse()
+                .map_err(|_| format!("invalid value for {}: {}", name, value))?;
+            if value < param.min || value > param.max {
+                return Err(format!(
+                    "invalid value for {}: {}, expected {} to {}",
+                    name, value, param.min, param.max
+                ));
+            }
+            params.push((name.to_string(), value));
+        }
+
+        Ok(Self(params))
+    }
+
+    /// The value of a parameter. The last assignment wins.
+    pub fn get(&self, param: &Param) -> u64 {
+        self.0
+            .iter()
+            .rev()
+            .find(|(name, _)| name == param.name)
+            .map_or(param.default, |&(_, v)| v)
+    }
+}
+
+/// Removes every `--param <name=value>` or `-p <name=value>` from the
+/// arguments and returns the assignments.
+pub fn take_params(args: &mut Vec<String>) -> Result<Vec<String>, String> {
+    let mut assignments = Vec::new();
+
+    while let Some(a) = crate::input::take_option(args, &["--param", "-p"])? {
+        assignments.push(a);
+    }
+
+    Ok(assignments)
+}
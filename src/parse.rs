@@ -0,0 +1,203 @@
+//! Parse errors with source locations and a small scanner for line based
+//! puzzle input.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error in the puzzle input. Lines and columns are counted from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// An error for input that ends before `expected`. `lines` is the number
+    /// of lines read so far.
+    pub fn end_of_input(lines: usize, expected: impl Into<String>) -> Self {
+        Self::new(lines + 1, 1, expected, "end of input")
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Moves the error down by `lines`, for parsers that only see the part
+    /// of the input after those lines.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Reads tokens from a single line of input and reports errors at the
+/// current position.
+#[derive(Clone, Debug)]
+pub struct Scanner<'a> {
+    line: &'a str,
+    lineno: usize,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str, lineno: usize) -> Self {
+        Self {
+            line,
+            lineno,
+            pos: 0,
+        }
+    }
+
+    /// Returns a scanner for every line of `s`.
+    pub fn lines(s: &'a str) -> impl Iterator<Item = Scanner<'a>> {
+        s.lines().enumerate().map(|(i, l)| Scanner::new(l, i + 1))
+    }
+
+    pub fn lineno(&self) -> usize {
+        self.lineno
+    }
+
+    /// The part of the line that hasn't been read yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pos == self.line.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(self.pos, expected)
+    }
+
+    /// An error at byte offset `pos` of the line.
+    pub fn error_at(&self, pos: usize, expected: impl Into<String>) -> ParseError {
+        let found = match self.line[pos..].chars().next() {
+            Some(c) => format!("'{}'", c),
+            None => String::from("end of line"),
+        };
+
+        let column = self.line[..pos].chars().count() + 1;
+        ParseError::new(self.lineno, column, expected, found)
+    }
+
+    /// Reads `tag` or fails without consuming anything.
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(tag) {
+            self.pos += tag.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("'{}'", tag)))
+        }
+    }
+
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Reads an optionally signed decimal number.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.pos;
+        let rest = self.rest();
+
+        let sign = if rest.starts_with('-') || rest.starts_with('+') {
+            1
+        } else {
+            0
+        };
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+
+        if digits == 0 {
+            return Err(self.error("a number"));
+        }
+
+        match rest[..sign + digits].parse::<T>() {
+            Ok(n) => {
+                self.pos += sign + digits;
+                Ok(n)
+            }
+            Err(_) => Err(self.error_at(start, "a number in range")),
+        }
+    }
+
+    /// Reads one or more items separated by `sep`.
+    pub fn separated<T>(
+        &mut self,
+        sep: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+
+        while self.rest().starts_with(sep) {
+            self.pos += sep.len();
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Fails unless the whole line has been read.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_done() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
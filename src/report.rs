@@ -0,0 +1,126 @@
+//! Output of the answers as text or as JSON records.
+
+use crate::solution::Solved;
+
+/// How answers are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// One `partN: answer` line per part.
+    Text,
+    /// A JSON array with one record per part.
+    Json,
+}
+
+/// Removes `--format <text|json>` from the arguments. Defaults to text.
+pub fn take_format(args: &mut Vec<String>) -> Result<Format, String> {
+    match crate::input::take_option(args, &["--format"])?.as_deref() {
+        None | Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(f) => Err(format!("unknown format: {}", f)),
+    }
+}
+
+/// Quotes a string for JSON.
+pub fn json_string(s: &str) -> String {
+    let mut r = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => r += "\\\"",
+            '\\' => r += "\\\\",
+            '\n' => r += "\\n",
+            '\t' => r += "\\t",
+            c if (c as u32) < 0x20 => r += &format!("\\u{:04x}", c as u32),
+            c => r.push(c),
+        }
+    }
+
+    r.push('"');
+    r
+}
+
+/// The result of one part of a day on one input. A part without an answer
+/// gives a record with its error, and a day that fails to parse or panics
+/// gives a single record with the error and no part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub input: String,
+    pub outcome: Result<Solved, String>,
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        match &self.outcome {
+            Ok(s) => match &s.answer {
+                Ok(answer) => format!(
+                    "{{\"day\": {}, \"part\": {}, \"answer\": {}, \"input\": {}, \"elapsed_ns\": {}}}",
+                    self.day,
+                    s.part,
+                    json_string(answer),
+                    json_string(&self.input),
+                    s.elapsed.as_nanos()
+                ),
+                Err(e) => format!(
+                    "{{\"day\": {}, \"part\": {}, \"input\": {}, \"error\": {}}}",
+                    self.day,
+                    s.part,
+                    json_string(&self.input),
+                    json_string(e)
+                ),
+            },
+            Err(e) => format!(
+                "{{\"day\": {}, \"input\": {}, \"error\": {}}}",
+                self.day,
+                json_string(&self.input),
+                json_string(e)
+            ),
+        }
+    }
+}
+
+/// Formats records as a JSON array with one record per line.
+pub fn json(records: &[Record]) -> String {
+    let records: Vec<String> = records
+        .iter()
+        .map(|r| format!("  {}", r.to_json()))
+        .collect();
+
+    if records.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n{}\n]\n", records.join(",\n"))
+    }
+}
+
+/// Prints the answers to the parts of a day in the given format. The errors
+/// of parts without an answer go to stderr in text, after the answers of the
+/// parts before them. Returns false if any part has no answer.
+pub fn print(format: Format, day: u32, input: &str, solved: Vec<Solved>) -> bool {
+    let answered = solved.iter().all(|s| s.answer.is_ok());
+
+    match format {
+        Format::Text => {
+            for s in solved {
+                match s.answer {
+                    Ok(answer) => println!("part{}: {}", s.part, answer),
+                    Err(e) => eprintln!("error: part {}: {}", s.part, e),
+                }
+            }
+        }
+        Format::Json => {
+            let records: Vec<Record> = solved
+                .into_iter()
+                .map(|s| Record {
+                    day,
+                    input: input.to_string(),
+                    outcome: Ok(s),
+                })
+                .collect();
+
+            print!("{}", json(&records));
+        }
+    }
+
+    answered
+}
@@ -0,0 +1,318 @@
+//! Adding a new day: its module from a template, its binary and its
+//! registration with the runner, the tests and the answer manifest.
+//!
+//! The template parses the input into lines, answers 0 to both parts and
+//! generates empty inputs, so the new day builds and passes the tests until
+//! it's filled in. Its sample test checks those answers on the empty sample,
+//! until the example and the answers from the puzzle replace them.
+
+use std::fs;
+use std::path::Path;
+
+/// A file to write with its path relative to the repository root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    pub path: String,
+    pub contents: String,
+}
+
+/// The module of a day with the puzzle's title, if known.
+pub fn module(day: u32, title: &str) -> String {
+    let header = match title {
+        "" => format!("//! Day {}", day),
+        title => format!("//! Day {}: {}", day, title),
+    };
+
+    format!(
+        "{header}
+
+use crate::params::Params;
+use crate::parse::{{ParseError, Scanner}};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+pub fn parse(s: &str) -> Result<Vec<String>, ParseError> {{
+    Ok(Scanner::lines(s).map(|l| l.rest().to_string()).collect())
+}}
+
+pub fn part1(_lines: &[String]) -> u64 {{
+    0
+}}
+
+pub fn part2(_lines: &[String]) -> u64 {{
+    0
+}}
+
+/// Generates a random input with about `size` lines.
+pub fn generate(_rng: &mut Rng, _size: usize) -> String {{
+    String::new()
+}}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u32 = {day};
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(s: &str) -> Result<Vec<String>, ParseError> {{
+        parse(s)
+    }}
+
+    fn part1(input: &Vec<String>, _params: &Params) -> u64 {{
+        part1(input)
+    }}
+
+    fn part2(input: &Vec<String>, _params: &Params) -> u64 {{
+        part2(input)
+    }}
+
+    fn generate(rng: &mut Rng, size: usize) -> String {{
+        generate(rng, size)
+    }}
+}}
+"
+    )
+}
+
+/// The binary that solves only this day.
+pub fn main_rs(day: u32) -> String {
+    format!(
+        "use aoc2021::{{day{day}::Day{day}, solution}};
+
+fn main() {{
+    solution::main::<Day{day}>();
+}}
+"
+    )
+}
+
+fn already(day: u32, file: &str) -> String {
+    format!("day {} is already registered in {}", day, file)
+}
+
+/// An entry of a list in a file, such as a `[[bin]]` table of `Cargo.toml`.
+struct Entry<'a> {
+    begin: usize,
+    end: usize,
+    /// The day, or the name of entries that aren't days.
+    name: &'a str,
+}
+
+/// Finds the entries in `text[range]` that start with `start` followed by
+/// their name up to `end`. They continue up to the next `stop`, or up to the
+/// end of the text.
+fn entries<'a>(
+    text: &'a str,
+    range: (usize, usize),
+    start: &str,
+    end: &str,
+    stop: &str,
+) -> Vec<Entry<'a>> {
+    text[range.0..range.1]
+        .match_indices(start)
+        .filter_map(|(i, _)| {
+            let begin = range.0 + i;
+            let rest = &text[begin + start.len()..];
+            let name = &rest[..rest.find(end)?];
+            let end = text[begin..]
+                .find(stop)
+                .map_or(text.len(), |n| begin + n + stop.len());
+            Some(Entry { begin, end, name })
+        })
+        .collect()
+}
+
+/// Whether an entry comes after a day when the entries are sorted by day,
+/// with other entries last.
+fn by_number(day: u32) -> impl Fn(&str) -> bool {
+    move |name| name.parse::<u32>().map_or(true, |d| d > day)
+}
+
+/// Whether an entry comes after a day when the entries are sorted by name,
+/// as rustfmt sorts modules, so day12 comes before day2.
+fn by_name(day: u32) -> impl Fn(&str) -> bool {
+    move |name| name > day.to_string().as_str()
+}
+
+/// Inserts `new` before the first entry that comes after the day, or after
+/// the last entry. Entries separated by blank lines end `new` with one, which
+/// moves in front of it at the end of the file.
+fn insert(
+    text: &str,
+    entries: &[Entry],
+    day: u32,
+    after: impl Fn(&str) -> bool,
+    new: &str,
+    file: &str,
+) -> Result<String, String> {
+    if entries.iter().any(|e| e.name == day.to_string()) {
+        return Err(already(day, file));
+    }
+
+    let pos = match entries.iter().find(|e| after(e.name)) {
+        Some(e) => e.begin,
+        None => match entries.last() {
+            Some(e) => e.end,
+            None => return Err(format!("no days found in {}", file)),
+        },
+    };
+
+    let new = match new.strip_suffix("\n\n") {
+        Some(block) if pos == text.len() => format!("\n{}\n", block),
+        _ => new.to_string(),
+    };
+
+    Ok(format!("{}{}{}", &text[..pos], new, &text[pos..]))
+}
+
+/// Adds the binary of a day to `Cargo.toml`, in front of the runner.
+pub fn register_bin(cargo: &str, day: u32) -> Result<String, String> {
+    let bins = entries(cargo, (0, cargo.len()), "[[bin]]\nname = \"", "\"", "\n\n");
+    let new = format!(
+        "[[bin]]\nname = \"{}\"\npath = \"src/{}/main.rs\"\n\n",
+        day, day
+    );
+
+    insert(cargo, &bins, day, by_number(day), &new, "Cargo.toml")
+}
+
+/// Adds the module of a day to `src/lib.rs`.
+pub fn register_module(lib: &str, day: u32) -> Result<String, String> {
+    let mods = entries(lib, (0, lib.len()), "#[path = \"", "/", ";\n");
+    let new = format!("#[path = \"{}/mod.rs\"]\npub mod day{};\n", day, day);
+
+    insert(lib, &mods, day, by_name(day), &new, "src/lib.rs")
+}
+
+/// Adds a day to the list of days of the runner in `src/days.rs`.
+pub fn register_day(days: &str, day: u32) -> Result<String, String> {
+    let file = "src/days.rs";
+    let begin = days
+        .find("pub const DAYS: &[Day] = &[\n")
+        .ok_or_else(|| format!("no list of days found in {}", file))?;
+    let end = begin + days[begin..].find("];").unwrap_or(days.len() - begin);
+
+    let list = entries(days, (begin, end), "    day::<day", ":", "\n");
+    let new = format!("    day::<day{}::Day{}>(),\n", day, day);
+
+    insert(days, &list, day, by_number(day), &new, file)
+}
+
+/// Adds the sample test of a day to `tests/samples.rs`, whose answers of 0
+/// are to be replaced with the ones from the puzzle.
+pub fn register_sample(samples: &str, day: u32) -> Result<String, String> {
+    let file = "tests/samples.rs";
+
+    let uses = entries(samples, (0, samples.len()), "use day", ":", "\n");
+    let new = format!("use day{}::Day{};\n", day, day);
+    let samples = insert(samples, &uses, day, by_name(day), &new, file)?;
+
+    // ignored tests have their reason between the attribute and the name,
+    // so every test starts at the attribute before its name
+    let mut tests = entries(&samples, (0, samples.len()), "\nfn day", "_", "}\n\n");
+    for test in &mut tests {
+        test.begin = samples[..test.begin].rfind("#[test]").unwrap_or(test.begin);
+    }
+
+    let new = format!(
+        "#[test]\n\
+         fn day{day}_sample() {{\n    \
+             check::<Day{day}>(\"test.txt\", 0, 0);\n\
+         }}\n\n",
+    );
+
+    insert(&samples, &tests, day, by_number(day), &new, file)
+}
+
+/// Adds a table without answers for the sample of a day to `answers.toml`.
+pub fn register_answers(answers: &str, day: u32) -> Result<String, String> {
+    let file = "answers.toml";
+    let tables = entries(
+        answers,
+        (0, answers.len()),
+        "[[answer]]\nday = ",
+        "\n",
+        "\n\n",
+    );
+
+    let new = format!(
+        "[[answer]]\nday = {}\ninput = \"src/{}/test.txt\"\n\n",
+        day, day
+    );
+
+    insert(answers, &tables, day, by_number(day), &new, file)
+}
+
+/// Adds a day to the contents of a file.
+type Register = fn(&str, u32) -> Result<String, String>;
+
+/// The files to write for a new day, given a function that reads the
+/// current contents of a file of the repository.
+pub fn files(
+    day: u32,
+    title: &str,
+    read: impl Fn(&str) -> Result<String, String>,
+) -> Result<Vec<File>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day: {}", day));
+    }
+
+    let registries: [(&str, Register); 5] = [
+        ("Cargo.toml", register_bin),
+        ("src/lib.rs", register_module),
+        ("src/days.rs", register_day),
+        ("tests/samples.rs", register_sample),
+        ("answers.toml", register_answers),
+    ];
+
+    let mut files = vec![
+        File {
+            path: format!("src/{}/mod.rs", day),
+            contents: module(day, title),
+        },
+        File {
+            path: format!("src/{}/main.rs", day),
+            contents: main_rs(day),
+        },
+        File {
+            path: format!("src/{}/test.txt", day),
+            contents: String::new(),
+        },
+        File {
+            path: format!("src/{}/data.txt", day),
+            contents: String::new(),
+        },
+    ];
+
+    for (path, register) in registries {
+        files.push(File {
+            path: path.to_string(),
+            contents: register(&read(path)?, day)?,
+        });
+    }
+
+    Ok(files)
+}
+
+/// Adds a day to the repository at `root` and returns the paths of the
+/// files written. Nothing is written if the day exists already.
+pub fn create(root: &Path, day: u32, title: &str) -> Result<Vec<String>, String> {
+    let dir = root.join("src").join(day.to_string());
+    if dir.exists() {
+        return Err(format!("{} exists already", dir.display()));
+    }
+
+    let files = files(day, title, |path| {
+        fs::read_to_string(root.join(path)).map_err(|e| format!("could not read {}: {}", path, e))
+    })?;
+
+    fs::create_dir_all(&dir).map_err(|e| format!("could not create {}: {}", dir.display(), e))?;
+    for f in &files {
+        fs::write(root.join(&f.path), &f.contents)
+            .map_err(|e| format!("could not write {}: {}", f.path, e))?;
+    }
+
+    Ok(files.into_iter().map(|f| f.path).collect())
+}
@@ -0,0 +1,194 @@
+//! Shortest path searches over graphs given by a neighbor function.
+//!
+//! Every search starts from any number of start nodes and stops at the first
+//! node for which `is_goal` holds, so several goals are just a predicate that
+//! accepts all of them.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A shortest path to a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found<N, C> {
+    /// The total cost of the path.
+    pub cost: C,
+    /// The nodes of the path, from a start node to the goal, both included.
+    pub path: Vec<N>,
+    /// The number of nodes taken from the queue before the goal was reached.
+    pub expanded: usize,
+}
+
+/// The nodes seen so far, with the node they were reached from and the cost
+/// to reach them.
+struct Visited<N, C> {
+    index: HashMap<N, usize>,
+    nodes: Vec<(N, usize, C)>,
+}
+
+const NO_PARENT: usize = usize::MAX;
+
+impl<N: Eq + Hash + Clone, C: Copy> Visited<N, C> {
+    fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Vec<N> {
+        let mut path = Vec::new();
+
+        while i != NO_PARENT {
+            path.push(self.nodes[i].0.clone());
+            i = self.nodes[i].1;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/// Finds a cheapest path with Dijkstra's algorithm. `neighbors` returns the
+/// nodes reachable from a node together with the cost of each step, which
+/// must not be negative. Returns `None` if no goal is reachable.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// Finds a cheapest path with A*. Like [`dijkstra`], but `heuristic`
+/// estimates the remaining cost from a node to the nearest goal. The result
+/// is only guaranteed to be cheapest if the estimate never exceeds the real
+/// cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    // Ordered by estimated total cost. Ties go to the node with the highest
+    // cost so far, which is the closest to a goal by the estimate.
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(e) = visited.index.entry(start.clone()) {
+            let i = visited.nodes.len();
+            e.insert(i);
+            queue.push(Reverse((heuristic(&start), Reverse(C::default()), i)));
+            visited.nodes.push((start, NO_PARENT, C::default()));
+        }
+    }
+
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, Reverse(cost), i))) = queue.pop() {
+        if cost > visited.nodes[i].2 {
+            // Reached again more cheaply since this entry was queued
+            continue;
+        }
+
+        expanded += 1;
+
+        let node = visited.nodes[i].0.clone();
+        if is_goal(&node) {
+            return Some(Found {
+                cost,
+                path: visited.path(i),
+                expanded,
+            });
+        }
+
+        for (n, step) in neighbors(&node) {
+            let next = cost + step;
+
+            match visited.index.entry(n) {
+                Entry::Vacant(e) => {
+                    let j = visited.nodes.len();
+                    let n = e.key().clone();
+                    e.insert(j);
+                    queue.push(Reverse((next + heuristic(&n), Reverse(next), j)));
+                    visited.nodes.push((n, i, next));
+                }
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if next < visited.nodes[j].2 {
+                        visited.nodes[j].1 = i;
+                        visited.nodes[j].2 = next;
+                        let estimate = next + heuristic(&visited.nodes[j].0);
+                        queue.push(Reverse((estimate, Reverse(next), j)));
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds a path with the fewest steps with a breadth-first search. The cost
+/// of the result is the number of steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(e) = visited.index.entry(start.clone()) {
+            let i = visited.nodes.len();
+            e.insert(i);
+            queue.push_back(i);
+            visited.nodes.push((start, NO_PARENT, 0));
+        }
+    }
+
+    let mut expanded = 0;
+
+    while let Some(i) = queue.pop_front() {
+        expanded += 1;
+
+        let (node, _, steps) = visited.nodes[i].clone();
+        if is_goal(&node) {
+            return Some(Found {
+                cost: steps,
+                path: visited.path(i),
+                expanded,
+            });
+        }
+
+        for n in neighbors(&node) {
+            if let Entry::Vacant(e) = visited.index.entry(n) {
+                let j = visited.nodes.len();
+                let n = e.key().clone();
+                e.insert(j);
+                queue.push_back(j);
+                visited.nodes.push((n, i, steps + 1));
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,170 @@
+//! The interface every day implements.
+
+use crate::animate::Frames;
+use crate::counter::Counter;
+use crate::gif::Movie;
+use crate::image::Image;
+use crate::input;
+use crate::params::{self, Param, Params};
+use crate::parse::ParseError;
+use crate::report;
+use crate::rng::Rng;
+use std::fmt::Display;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// The solver of one day. The input is parsed once and both parts are
+/// solved on the result.
+pub trait Solution {
+    /// The number of the day.
+    const DAY: u32;
+
+    /// The parsed puzzle input.
+    type Input;
+
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// Parameters of the puzzle, such as the number of steps to simulate,
+    /// that can be changed from the command line.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input, params: &Params) -> Self::Answer1;
+
+    fn part2(input: &Self::Input, params: &Params) -> Self::Answer2;
+
+    /// Generates a random input that `parse` accepts. What `size` means
+    /// depends on the day, usually it's the number of lines or the side of a
+    /// square map.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    /// The states of the simulation as frames for the terminal, for days
+    /// that simulate a number of steps.
+    fn frames<'a>(_input: &'a Self::Input, _params: &Params) -> Option<Frames<'a>> {
+        None
+    }
+
+    /// A picture of the puzzle, for days on a grid.
+    fn image(_input: &Self::Input, _params: &Params) -> Option<Image> {
+        None
+    }
+
+    /// The states of the simulation as an animated picture.
+    fn movie(_input: &Self::Input, _params: &Params) -> Option<Movie> {
+        None
+    }
+}
+
+/// The answer to a part, or why there is none, such as a count that got too
+/// large for its type.
+pub trait Answer {
+    fn answer(&self) -> Result<String, String>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn answer(&self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_answer!(i32, i64, u32, usize, String);
+
+impl<C: Counter> Answer for C {
+    fn answer(&self) -> Result<String, String> {
+        Ok(self.to_string())
+    }
+}
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn answer(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => answer.answer(),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// The answer to one part of a day, or why it has none, and the time it
+/// took to solve it, not counting parsing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solved {
+    pub part: u32,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/// Parses the puzzle input once and solves the given parts, which have to be
+/// 1 or 2, in order. A part without an answer doesn't keep the others from
+/// being solved.
+pub fn solve<S: Solution>(
+    s: &str,
+    parts: &[u32],
+    params: &Params,
+) -> Result<Vec<Solved>, ParseError> {
+    let input = S::parse(s)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input, params).answer(),
+                2 => S::part2(&input, params).answer(),
+                _ => panic!("day {} has no part {}", S::DAY, part),
+            };
+
+            Solved {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+/// Entry point of the binary of a day. Reads the input given on the command
+/// line, or the puzzle input of the day, and prints both answers in the
+/// requested format.
+pub fn main<S: Solution>() {
+    let mut args: Vec<String> = std::env::args().collect();
+    let name = args.remove(0);
+
+    let options = input::take_path(&mut args).and_then(|path| {
+        let format = report::take_format(&mut args)?;
+        let params = Params::parse(&params::take_params(&mut args)?, S::PARAMS)?;
+        Ok((path, format, params))
+    });
+
+    let (path, format, params) = match options {
+        Ok(options) if args.is_empty() => options,
+        Ok(_) => {
+            eprintln!(
+                "usage: {} [--input <path>|-] [--format text|json] [--param <name=value>]...",
+                name
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let input = match path {
+        Some(path) => input::load(&path),
+        None => input::load_data(&format!("src/{}/data.txt", S::DAY)),
+    };
+    let solved = input.parse(|s| solve::<S>(s, &[1, 2], &params));
+
+    if !report::print(format, S::DAY, &input.path, solved) {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,247 @@
+//! Points, rotations and cuboids in three dimensions.
+
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+
+/// A point or a vector with integer coordinates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The sum of the absolute coordinates, which is the Manhattan distance
+    /// from the origin.
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    /// The square of the Euclidean length, which stays an integer.
+    pub fn length_squared(self) -> i64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn coords(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Combines the coordinates of two points one by one, such as with
+    /// `i64::min` for the corner of a box.
+    pub fn zip_with(self, other: Point3, f: impl Fn(i64, i64) -> i64) -> Point3 {
+        Point3::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+    }
+}
+
+impl From<[i64; 3]> for Point3 {
+    fn from([x, y, z]: [i64; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// A rotation by multiples of 90 degrees around the axes, which turns every
+/// axis into a possibly negated other one. There are 24 of them, one for
+/// every direction the x axis can face combined with every direction of the
+/// y axis at a right angle to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    /// The axis of the rotated point every coordinate is taken from.
+    axes: [usize; 3],
+    /// Whether the coordinate is negated, as 1 or -1.
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        axes: [0, 1, 2],
+        signs: [1, 1, 1],
+    };
+
+    /// All 24 rotations, starting with the identity.
+    pub fn all() -> Vec<Rotation> {
+        let permutations = [
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [2, 1, 0],
+            [1, 0, 2],
+        ];
+
+        let mut rotations = Vec::new();
+        for (i, &axes) in permutations.iter().enumerate() {
+            // the last three permutations swap two axes, which mirrors
+            let parity = if i < 3 { 1 } else { -1 };
+
+            for signs in 0..8 {
+                let signs = [0, 1, 2].map(|bit| if signs >> bit & 1 == 0 { 1 } else { -1 });
+
+                // an odd number of negated axes mirrors as well
+                if parity * signs.iter().product::<i64>() == 1 {
+                    rotations.push(Rotation { axes, signs });
+                }
+            }
+        }
+
+        rotations
+    }
+
+    pub fn apply(&self, p: Point3) -> Point3 {
+        let c = p.coords();
+
+        Point3::from([0, 1, 2].map(|i| self.signs[i] * c[self.axes[i]]))
+    }
+
+    /// The rotation that applies `self` first and `other` after it.
+    pub fn then(&self, other: &Rotation) -> Rotation {
+        Rotation {
+            axes: [0, 1, 2].map(|i| self.axes[other.axes[i]]),
+            signs: [0, 1, 2].map(|i| other.signs[i] * self.signs[other.axes[i]]),
+        }
+    }
+
+    /// The rotation that undoes this one.
+    pub fn inverse(&self) -> Rotation {
+        let mut inverse = Rotation::IDENTITY;
+
+        for i in 0..3 {
+            inverse.axes[self.axes[i]] = i;
+            inverse.signs[self.axes[i]] = self.signs[i];
+        }
+
+        inverse
+    }
+}
+
+/// A box of integer points that has its sides parallel to the axes, from
+/// `min` to `max` with both corners included. It is never empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Cuboid {
+    /// The cuboid between two corners. Every coordinate of `min` has to be
+    /// at most the one of `max`.
+    pub fn new(min: Point3, max: Point3) -> Self {
+        assert!(
+            min.coords().iter().zip(max.coords()).all(|(a, b)| *a <= b),
+            "{} is beyond {}",
+            min,
+            max
+        );
+
+        Self { min, max }
+    }
+
+    /// The cuboid from `-radius` to `radius` along every axis.
+    pub fn around_origin(radius: i64) -> Self {
+        Self::new(
+            Point3::new(-radius, -radius, -radius),
+            Point3::new(radius, radius, radius),
+        )
+    }
+
+    /// The number of points inside.
+    pub fn volume(&self) -> i64 {
+        let size = self.max - self.min;
+
+        (size.x + 1) * (size.y + 1) * (size.z + 1)
+    }
+
+    pub fn contains(&self, p: Point3) -> bool {
+        self.min.zip_with(p, i64::max) == p && self.max.zip_with(p, i64::min) == p
+    }
+
+    /// The points inside both cuboids, if there are any.
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = self.min.zip_with(other.min, i64::max);
+        let max = self.max.zip_with(other.max, i64::min);
+
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            return None;
+        }
+
+        Some(Cuboid { min, max })
+    }
+
+    /// The points inside `self` but not `other`, as up to 6 cuboids that
+    /// don't overlap.
+    pub fn subtract(&self, other: &Cuboid) -> Vec<Cuboid> {
+        let Some(cut) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        // slices off the parts before and after the cut along every axis
+        // in turn, which leaves the cut itself
+        let mut pieces = Vec::new();
+        let (mut min, mut max) = (self.min.coords(), self.max.coords());
+        let (cut_min, cut_max) = (cut.min.coords(), cut.max.coords());
+
+        for axis in 0..3 {
+            if min[axis] < cut_min[axis] {
+                let mut before = max;
+                before[axis] = cut_min[axis] - 1;
+                pieces.push(Cuboid::new(min.into(), before.into()));
+                min[axis] = cut_min[axis];
+            }
+            if max[axis] > cut_max[axis] {
+                let mut after = min;
+                after[axis] = cut_max[axis] + 1;
+                pieces.push(Cuboid::new(after.into(), max.into()));
+                max[axis] = cut_max[axis];
+            }
+        }
+
+        pieces
+    }
+}
+
+impl fmt::Display for Cuboid {
+    /// Writes the ranges of the coordinates like `x=1..2,y=3..4,z=5..6`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "x={}..{},y={}..{},z={}..{}",
+            self.min.x, self.max.x, self.min.y, self.max.y, self.min.z, self.max.z
+        )
+    }
+}
@@ -0,0 +1,86 @@
+//! Checks the terminal player and the animations of the days.
+
+use aoc2021::animate::{self, Control, Frames, Playback};
+use aoc2021::days;
+use aoc2021::params::Params;
+use std::sync::mpsc;
+
+fn frames(n: usize) -> Frames<'static> {
+    Box::new((0..n).map(|i| format!("frame {}\nrow", i)))
+}
+
+fn fast() -> Playback {
+    Playback {
+        fps: 1000.0,
+        paused: false,
+    }
+}
+
+#[test]
+fn keys() {
+    assert_eq!(Control::from_key(b' '), Some(Control::Pause));
+    assert_eq!(Control::from_key(b'n'), Some(Control::Step));
+    assert_eq!(Control::from_key(b'+'), Some(Control::Faster));
+    assert_eq!(Control::from_key(b'-'), Some(Control::Slower));
+    assert_eq!(Control::from_key(b'q'), Some(Control::Quit));
+    assert_eq!(Control::from_key(0x03), Some(Control::Quit));
+    assert_eq!(Control::from_key(b'x'), None);
+}
+
+#[test]
+fn plays_every_frame() {
+    let (_, rx) = mpsc::channel();
+    let mut out = Vec::new();
+
+    assert_eq!(animate::play(frames(5), &mut out, &rx, fast()).unwrap(), 5);
+
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("frame 4\x1b[K\nrow\x1b[K\n"));
+    assert!(out.ends_with("\x1b[?25h\n"));
+}
+
+#[test]
+fn quit_and_step() {
+    let (tx, rx) = mpsc::channel();
+    tx.send(Control::Step).unwrap();
+    tx.send(Control::Step).unwrap();
+    tx.send(Control::Quit).unwrap();
+
+    let mut out = Vec::new();
+    let paused = Playback {
+        paused: true,
+        ..fast()
+    };
+    assert_eq!(animate::play(frames(5), &mut out, &rx, paused).unwrap(), 3);
+}
+
+/// Collects the frames of a day on its sample.
+fn animation(day: u32) -> Option<Vec<String>> {
+    let d = days::find(day).unwrap();
+    let text = std::fs::read_to_string(d.test_path()).unwrap();
+
+    let mut shown = Vec::new();
+    let animated = (d.animate)(&text, &Params::default(), &mut |f| shown.extend(f)).unwrap();
+
+    animated.then_some(shown)
+}
+
+#[test]
+fn days() {
+    let day6 = animation(6).unwrap();
+    assert_eq!(day6.len(), 257);
+    assert!(day6[256].starts_with("day 256  fish 26984457539\n"));
+
+    let day11 = animation(11).unwrap();
+    assert_eq!(day11.len(), 196);
+    assert!(day11[195].starts_with("step 195  flashes 100"));
+
+    let day17 = animation(17).unwrap();
+    assert!(day17[0].starts_with("velocity 6,9  step 0  position 0,0\n"));
+
+    let day20 = animation(20).unwrap();
+    assert_eq!(day20.len(), 51);
+    assert!(day20[2].starts_with("step 2  lit 35\n"));
+
+    assert_eq!(animation(5), None);
+}
@@ -0,0 +1,78 @@
+//! Checks every recorded answer in `answers.toml`.
+
+use aoc2021::answers::{self, Answer};
+use aoc2021::days;
+use aoc2021::params::Params;
+
+#[test]
+fn manifest() {
+    let answers = answers::load(answers::MANIFEST).unwrap();
+    assert!(!answers.is_empty());
+
+    for a in &answers {
+        let d = days::find(a.day).unwrap();
+        let text = std::fs::read_to_string(&a.input).unwrap();
+        let solved = (d.solve)(&text, &[a.part], &Params::default()).unwrap();
+
+        assert_eq!(
+            solved[0].answer,
+            Ok(a.expected.clone()),
+            "day {} part {} on {}",
+            a.day,
+            a.part,
+            a.input
+        );
+    }
+}
+
+#[test]
+fn parse_manifest() {
+    let answers = answers::parse(
+        "# comment\n\
+         [[answer]]\n\
+         day = 3\n\
+         input = \"src/3/test.txt\"  # sample\n\
+         part2 = \"230\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        answers,
+        [Answer {
+            day: 3,
+            part: 2,
+            input: String::from("src/3/test.txt"),
+            expected: String::from("230"),
+        }]
+    );
+}
+
+#[test]
+fn parse_escapes() {
+    let answers = answers::parse(
+        "[[answer]]\n\
+         day = 13\n\
+         input = \"src/13/\\\"test\\\\.txt\"\n\
+         part2 = \"#.#\\n.#.\\n\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(answers[0].input, "src/13/\"test\\.txt");
+    assert_eq!(answers[0].expected, "#.#\n.#.\n");
+
+    let e = answers::parse("[[answer]]\nday = 13\ninput = \"a\\tb\"\n").unwrap_err();
+    assert_eq!((e.line, e.column), (3, 12));
+}
+
+#[test]
+fn parse_manifest_errors() {
+    let e = answers::parse("[[answer]]\nday = 3\npart3 = \"1\"\n").unwrap_err();
+    assert_eq!((e.line, e.column), (3, 1));
+
+    let e = answers::parse("[[answer]]\nday = 3\npart1 = \"1\"\n").unwrap_err();
+    assert_eq!((e.line, e.column), (1, 1));
+    assert_eq!(e.expected, "'input' in table");
+
+    let e = answers::parse("day = 3\n").unwrap_err();
+    assert_eq!(e.expected, "'[[answer]]'");
+}
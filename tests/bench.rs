@@ -0,0 +1,40 @@
+use aoc2021::{bench, day1, days};
+
+#[test]
+fn measure_orders_stats() {
+    let t = bench::measure(
+        "1\n3\n2\n4\n5\n",
+        5,
+        day1::parse,
+        |n| day1::part1(n),
+        |n| day1::part2(n),
+    )
+    .unwrap();
+
+    for stats in [t.parse, t.part1, t.part2] {
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+    }
+}
+
+#[test]
+fn measure_reports_parse_errors() {
+    let d = days::find(1).unwrap();
+    let e = (d.bench)("1\nx\n", 1).unwrap_err();
+    assert_eq!((e.line, e.column), (2, 1));
+}
+
+#[test]
+fn json_report_lists_days() {
+    let t = bench::measure(
+        "1\n2\n",
+        1,
+        day1::parse,
+        |n| day1::part1(n),
+        |n| day1::part2(n),
+    )
+    .unwrap();
+    let json = bench::json_report(1, &[(1, String::from("src/1/test.txt"), t)]);
+
+    assert!(json.contains("\"runs\": 1"));
+    assert!(json.contains("{\"day\": 1, \"input\": \"src/1/test.txt\", \"parse\": {\"min_ns\": "));
+}
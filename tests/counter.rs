@@ -0,0 +1,124 @@
+//! Checks the counter types and the overflow detection of the days that count
+//! exponentially growing numbers.
+
+use aoc2021::counter::{BigUint, Counter, Overflow};
+use aoc2021::days;
+use aoc2021::params::Params;
+use aoc2021::rng::Rng;
+use aoc2021::*;
+
+fn big(n: u128) -> BigUint {
+    let high = BigUint::from_u64((n >> 64) as u64);
+    let shift = BigUint::from_u64(1 << 32).checked_mul(&BigUint::from_u64(1 << 32));
+
+    high.checked_mul(&shift.unwrap())
+        .and_then(|h| h.checked_add(&BigUint::from_u64(n as u64)))
+        .unwrap()
+}
+
+#[test]
+fn big_matches_u128() {
+    let mut rng = Rng::new(7);
+
+    for _ in 0..1000 {
+        let a = rng.next_u64() as u128 >> rng.below(64);
+        let b = rng.next_u64() as u128 >> rng.below(64);
+
+        assert_eq!(big(a).checked_add(&big(b)), Some(big(a + b)));
+        assert_eq!(big(a).checked_mul(&big(b)), Some(big(a * b)));
+        assert_eq!(big(a).checked_sub(&big(b)), a.checked_sub(b).map(big));
+        assert_eq!(big(a).cmp(&big(b)), a.cmp(&b));
+        assert_eq!(big(a * b).to_string(), (a * b).to_string());
+    }
+}
+
+#[test]
+fn big_display() {
+    assert_eq!(BigUint::zero().to_string(), "0");
+    assert_eq!(BigUint::from_u64(1_000_000_000).to_string(), "1000000000");
+
+    let factorial = (1..=30).fold(BigUint::from_u64(1), |f, n| {
+        f.checked_mul(&BigUint::from_u64(n)).unwrap()
+    });
+    assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+}
+
+#[test]
+fn primitive_overflow() {
+    assert_eq!(Counter::checked_add(&u64::MAX, &1), None);
+    assert_eq!(Counter::checked_sub(&0u64, &1), None);
+    assert_eq!(Counter::checked_mul(&u128::MAX, &2), None);
+}
+
+fn overflow(counter: &'static str, step: usize) -> Overflow {
+    Overflow { counter, step }
+}
+
+#[test]
+fn day6() {
+    let population = day6::parse("3,4,3,1,2").unwrap();
+
+    assert_eq!(
+        day6::simulate::<u64>(&population, 500),
+        Err(overflow("u64", 490))
+    );
+    assert_eq!(
+        day6::simulate::<BigUint>(&population, 700).map(|n| n.to_string()),
+        day6::simulate::<u128>(&population, 700).map(|n| n.to_string())
+    );
+    assert_eq!(
+        overflow("u64", 490).to_string(),
+        "the counts overflow u64 in step 490"
+    );
+}
+
+#[test]
+fn day14() {
+    let manual = day14::parse(&std::fs::read_to_string("src/14/test.txt").unwrap()).unwrap();
+
+    assert_eq!(day14::score::<u64>(&manual, 70), Err(overflow("u64", 64)));
+    assert_eq!(
+        day14::score::<BigUint>(&manual, 100).map(|n| n.to_string()),
+        day14::score::<u128>(&manual, 100).map(|n| n.to_string())
+    );
+}
+
+#[test]
+fn day21() {
+    // the sums of the universes overflow near the start of the game
+    assert_eq!(day21::dirac::<u64>((4, 8), 40), Err(overflow("u64", 7)));
+    assert_eq!(
+        day21::dirac::<BigUint>((4, 8), 30).map(|n| n.to_string()),
+        day21::dirac::<u128>((4, 8), 30).map(|n| n.to_string())
+    );
+}
+
+#[test]
+fn big_days_match() {
+    for d in days::BIG {
+        let text = std::fs::read_to_string(d.data_path()).unwrap();
+        let solved = (d.solve)(&text, &[1, 2], &Params::default()).unwrap();
+        let expected = (days::find(d.day).unwrap().solve)(&text, &[1, 2], &Params::default());
+
+        for (s, e) in solved.iter().zip(expected.unwrap()) {
+            assert_eq!(s.answer, e.answer, "day {}", d.day);
+        }
+    }
+}
+
+#[test]
+fn solver_reports_overflow() {
+    let d = days::find(6).unwrap();
+    let params = Params::parse(&[String::from("days2=500")], d.params).unwrap();
+
+    // part 1 is answered even though part 2 overflows
+    let solved = (d.solve)("3,4,3,1,2\n", &[1, 2], &params).unwrap();
+    assert_eq!(solved[0].answer, Ok(String::from("5934")));
+    assert_eq!(
+        solved[1].answer,
+        Err(String::from("the counts overflow u64 in step 490"))
+    );
+
+    let big = days::find_big(6).unwrap();
+    assert!((big.solve)("3,4,3,1,2\n", &[1, 2], &params).is_ok());
+}
@@ -0,0 +1,92 @@
+//! Checks that the generated inputs are accepted by the parsers and solvers.
+
+use aoc2021::days::{self, Day};
+use aoc2021::params::Params;
+use aoc2021::rng::Rng;
+
+/// Solves the parts of a day on a generated input. The octopuses of day 11
+/// rarely all flash at once on random caverns, which part 2 reports after
+/// fewer steps than usual to keep the test fast.
+fn solve(d: &Day, text: &str, parts: &[u32]) -> Result<(), String> {
+    let params = match d.day {
+        11 => Params::parse(&[String::from("max-steps=1000")], d.params).unwrap(),
+        _ => Params::default(),
+    };
+
+    for s in (d.solve)(text, parts, &params).map_err(|e| e.to_string())? {
+        match s.answer {
+            Ok(_) => {}
+            Err(e) if d.day == 11 && s.part == 2 => {
+                assert!(e.contains("don't all flash at once"), "{}", e);
+            }
+            Err(e) => return Err(format!("part {}: {}", s.part, e)),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn generated_inputs_solve() {
+    for d in days::DAYS {
+        // day 21 part 2 takes seconds per input
+        let parts: &[u32] = match d.day {
+            21 => &[1],
+            _ => &[1, 2],
+        };
+
+        for seed in 0..5 {
+            let text = (d.generate)(&mut Rng::new(seed), 12);
+
+            if let Err(e) = solve(d, &text, parts) {
+                panic!("day {} seed {}: {}\n{}", d.day, seed, e, text);
+            }
+        }
+    }
+}
+
+#[test]
+fn small_generated_inputs_solve() {
+    for d in days::DAYS {
+        let parts: &[u32] = match d.day {
+            21 => &[1],
+            _ => &[1, 2],
+        };
+
+        for size in 0..=3 {
+            for seed in 0..5 {
+                let text = (d.generate)(&mut Rng::new(seed), size);
+
+                if let Err(e) = solve(d, &text, parts) {
+                    panic!("day {} size {} seed {}: {}\n{}", d.day, size, seed, e, text);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generation_is_seeded() {
+    for d in days::DAYS {
+        let a = (d.generate)(&mut Rng::new(7), 20);
+        let b = (d.generate)(&mut Rng::new(7), 20);
+        assert_eq!(a, b, "day {}", d.day);
+    }
+
+    let d = days::find(9).unwrap();
+    assert_ne!(
+        (d.generate)(&mut Rng::new(1), 20),
+        (d.generate)(&mut Rng::new(2), 20)
+    );
+}
+
+#[test]
+fn large_packets() {
+    let d = days::find(16).unwrap();
+
+    for seed in 0..3 {
+        let text = (d.generate)(&mut Rng::new(seed), 5000);
+        assert!(text.len() > 1000);
+        (d.solve)(&text, &[1, 2], &Params::default()).unwrap();
+    }
+}
@@ -0,0 +1,192 @@
+//! Checks the GIF encoder by decoding what it writes and the animations of
+//! the days.
+
+use aoc2021::days;
+use aoc2021::gif::{self, Movie};
+use aoc2021::grid::Grid;
+use aoc2021::params::Params;
+use aoc2021::rng::Rng;
+
+/// Decodes LZW data the way GIF decoders do: the table gets an entry for
+/// every code after the first and the codes get one bit wider once the table
+/// has an entry for every code of the current width.
+fn unlzw(min_code_size: u8, data: &[u8]) -> Vec<u8> {
+    let clear = 1usize << min_code_size;
+    let end = clear + 1;
+
+    let (mut bits, mut len, mut pos) = (0u32, 0, 0);
+    let mut read = |width: u32| {
+        while len < width {
+            bits |= (data[pos] as u32) << len;
+            pos += 1;
+            len += 8;
+        }
+        let code = bits & ((1 << width) - 1);
+        bits >>= width;
+        len -= width;
+        code as usize
+    };
+
+    let mut table: Vec<Vec<u8>> = Vec::new();
+    let mut width = min_code_size as u32 + 1;
+    let mut prev: Option<usize> = None;
+    let mut out = Vec::new();
+
+    loop {
+        let code = read(width);
+
+        if code == clear {
+            table = (0..clear).map(|i| vec![i as u8]).collect();
+            table.extend([vec![], vec![]]);
+            width = min_code_size as u32 + 1;
+            prev = None;
+            continue;
+        }
+        if code == end {
+            return out;
+        }
+
+        let entry = match prev {
+            None => table[code].clone(),
+            Some(p) => {
+                let entry = table.get(code).cloned().unwrap_or_else(|| {
+                    assert_eq!(code, table.len(), "code out of range");
+                    let mut e = table[p].clone();
+                    e.push(table[p][0]);
+                    e
+                });
+                if table.len() < 4096 {
+                    let mut new = table[p].clone();
+                    new.push(entry[0]);
+                    table.push(new);
+                }
+                entry
+            }
+        };
+
+        if table.len() == 1 << width && width < 12 {
+            width += 1;
+        }
+
+        out.extend_from_slice(&entry);
+        prev = Some(code);
+    }
+}
+
+#[test]
+fn lzw_round_trip() {
+    let mut rng = Rng::new(1);
+
+    for min_code_size in 2..=8u8 {
+        for len in [0, 1, 2, 100, 5000, 70000] {
+            // few distinct values give long runs and a full table
+            let values = rng.between(1, 1 << min_code_size) as usize;
+            let indices: Vec<u8> = (0..len).map(|_| rng.below(values) as u8).collect();
+
+            let data = gif::lzw(min_code_size, &indices);
+            assert_eq!(
+                unlzw(min_code_size, &data),
+                indices,
+                "{} {}",
+                min_code_size,
+                len
+            );
+        }
+    }
+}
+
+/// Splits a GIF into its blocks and returns the screen size and the pixels of
+/// every frame.
+fn decode(data: &[u8]) -> ((usize, usize), Vec<Vec<u8>>) {
+    assert!(data.starts_with(b"GIF89a"));
+    let size = |i: usize| u16::from_le_bytes([data[i], data[i + 1]]) as usize;
+    let screen = (size(6), size(8));
+
+    let mut i = 13 + 3 * (2 << (data[10] & 7));
+    let mut frames = Vec::new();
+
+    let sub_blocks = |i: &mut usize| {
+        let mut bytes = Vec::new();
+        while data[*i] != 0 {
+            bytes.extend_from_slice(&data[*i + 1..*i + 1 + data[*i] as usize]);
+            *i += data[*i] as usize + 1;
+        }
+        *i += 1;
+        bytes
+    };
+
+    loop {
+        match data[i] {
+            0x21 => {
+                i += 2;
+                sub_blocks(&mut i);
+            }
+            0x2c => {
+                assert_eq!((size(i + 5), size(i + 7)), screen);
+                let min_code_size = data[i + 10];
+                i += 11;
+                frames.push(unlzw(min_code_size, &sub_blocks(&mut i)));
+            }
+            0x3b => return (screen, frames),
+            b => panic!("unexpected block {:x}", b),
+        }
+    }
+}
+
+#[test]
+fn movie() {
+    let movie = Movie {
+        palette: vec![[0, 0, 0], [255, 255, 255], [255, 0, 0]],
+        frames: vec![Grid::new(2, 1, vec![0, 1]), Grid::new(2, 1, vec![2, 0])],
+    };
+    let data = movie.encode(5, 2);
+
+    // four colours in the table
+    assert_eq!(data[10], 0xf1);
+    assert_eq!(&data[13..25], [0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0]);
+    assert!(data.ends_with(b"\x3b"));
+
+    let (screen, frames) = decode(&data);
+    assert_eq!(screen, (4, 2));
+    assert_eq!(frames, [[0, 0, 1, 1, 0, 0, 1, 1], [2, 2, 0, 0, 2, 2, 0, 0]]);
+}
+
+/// Records a day on its sample.
+fn record(day: u32) -> Option<Movie> {
+    let d = days::find(day).unwrap();
+    let text = std::fs::read_to_string(d.test_path()).unwrap();
+
+    (d.movie)(&text, &Params::default()).unwrap()
+}
+
+#[test]
+fn days() {
+    let octopuses = record(11).unwrap();
+    assert_eq!(octopuses.frames.len(), 196);
+    assert_eq!(octopuses.frames[0][(0, 0)], 5);
+    assert!(octopuses.frames[195].iter().all(|&v| v == 0));
+
+    let image = record(20).unwrap();
+    assert_eq!(image.frames.len(), 51);
+    assert!(image
+        .frames
+        .iter()
+        .all(|f| (f.width(), f.height()) == (105, 105)));
+    assert_eq!(image.frames[2].iter().filter(|&&v| v % 2 == 1).count(), 35);
+
+    let (_, frames) = decode(&image.encode(10, 1));
+    assert_eq!(frames.len(), 51);
+
+    assert_eq!(record(5), None);
+}
+
+#[test]
+fn frames_are_capped() {
+    let d = days::find(11).unwrap();
+    let text = std::fs::read_to_string(d.test_path()).unwrap();
+    let params = Params::parse(&[String::from("max-frames=10")], d.params).unwrap();
+
+    // the start and ten steps, long before all octopuses flash at once
+    let movie = (d.movie)(&text, &params).unwrap().unwrap();
+    assert_eq!(movie.frames.len(), 11);
+}
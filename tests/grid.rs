@@ -0,0 +1,75 @@
+use aoc2021::day15;
+use aoc2021::grid::Grid;
+
+#[test]
+fn neighbors_stay_inside() {
+    let grid = Grid::filled(3, 2, 0);
+
+    let mut n: Vec<_> = grid.neighbors4((0, 0)).collect();
+    n.sort_unstable();
+    assert_eq!(n, [(0, 1), (1, 0)]);
+
+    let mut n: Vec<_> = grid.neighbors8((2, 1)).collect();
+    n.sort_unstable();
+    assert_eq!(n, [(1, 0), (1, 1), (2, 0)]);
+
+    assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbors4((3, 0)).count(), 1);
+}
+
+#[test]
+fn parse_digits() {
+    let grid = Grid::parse_digits("123\n456\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.to_string(), "123\n456\n");
+
+    let rows: Vec<&[u8]> = grid.rows().collect();
+    assert_eq!(rows, [[1, 2, 3], [4, 5, 6]]);
+
+    let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+    assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+}
+
+#[test]
+fn parse_pixels() {
+    let grid = Grid::parse_pixels("#..\n.##\n").unwrap();
+    assert!(grid[(0, 0)] && !grid[(0, 1)] && grid[(2, 1)]);
+    assert_eq!(
+        grid.display_with(|&on| if on { '#' } else { '.' }),
+        "#..\n.##\n"
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let e = Grid::parse_digits("123\n45\n").unwrap_err();
+    assert_eq!(e.to_string(), "2:3: expected 3 cells, found end of line");
+
+    let e = Grid::parse_digits("123\n4567\n").unwrap_err();
+    assert_eq!(e.to_string(), "2:4: expected 3 cells, found '7'");
+
+    let e = Grid::parse_digits("12x\n").unwrap_err();
+    assert_eq!(e.to_string(), "1:3: expected end of line, found 'x'");
+
+    let e = Grid::parse_pixels("").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "1:1: expected '#' or '.', found end of input"
+    );
+}
+
+#[test]
+fn risk_levels_start_at_one() {
+    let e = day15::parse("119\n101\n").unwrap_err();
+    assert_eq!(e.to_string(), "2:2: expected 3 cells, found '0'");
+
+    let e = day15::parse("0\n").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "1:1: expected a digit from 1 to 9, found '0'"
+    );
+
+    assert!(day15::parse("119\n191\n").is_ok());
+}
@@ -0,0 +1,127 @@
+//! Checks the image encoders and the pictures of the days.
+
+use aoc2021::days;
+use aoc2021::grid::Grid;
+use aoc2021::image::{self, Encoding, Image};
+use aoc2021::params::Params;
+use aoc2021::*;
+
+#[test]
+fn checksums() {
+    assert_eq!(image::crc32(b"123456789"), 0xcbf4_3926);
+    assert_eq!(image::adler32(b"Wikipedia"), 0x11e6_0398);
+    assert_eq!(image::adler32(b""), 1);
+}
+
+#[test]
+fn stored_blocks() {
+    assert_eq!(
+        image::zlib_stored(b""),
+        [0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]
+    );
+
+    let data = vec![7; 70000];
+    let z = image::zlib_stored(&data);
+    assert_eq!(z.len(), 2 + 5 + 65535 + 5 + 4465 + 4);
+    assert_eq!(z[2..7], [0, 0xff, 0xff, 0, 0]);
+    assert_eq!(z[65542..65547], [1, 0x71, 0x11, 0x8e, 0xee]);
+}
+
+fn checkerboard() -> Image {
+    Grid::new(
+        2,
+        2,
+        vec![image::BLACK, image::WHITE, image::WHITE, image::BLACK],
+    )
+}
+
+#[test]
+fn ppm() {
+    let data = image::encode(&checkerboard(), Encoding::Ppm);
+
+    assert!(data.starts_with(b"P6\n2 2\n255\n"));
+    assert_eq!(data[11..], [0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0]);
+}
+
+#[test]
+fn png() {
+    let data = image::encode(&checkerboard(), Encoding::Png);
+
+    assert!(data.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x02\x08\x02"));
+    assert!(data.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+}
+
+#[test]
+fn encoding_from_path() {
+    assert_eq!(Encoding::from_path("out.ppm"), Ok(Encoding::Ppm));
+    assert_eq!(Encoding::from_path("dir/out.PNG"), Ok(Encoding::Png));
+    assert!(Encoding::from_path("out.gif").is_err());
+}
+
+#[test]
+fn scale() {
+    let scaled = image::scale(&checkerboard(), 3);
+
+    assert_eq!((scaled.width(), scaled.height()), (6, 6));
+    assert_eq!(scaled[(2, 2)], image::BLACK);
+    assert_eq!(scaled[(3, 2)], image::WHITE);
+}
+
+#[test]
+fn colours() {
+    assert_eq!(image::heat(0, 9), image::BLACK);
+    assert_eq!(image::heat(9, 9), image::WHITE);
+    assert_eq!(image::gray(5, 10), [128, 128, 128]);
+    assert_ne!(image::palette(1), image::palette(2));
+}
+
+/// Draws a day on its sample.
+fn picture(day: u32, a: &[&str]) -> Option<Image> {
+    let d = days::find(day).unwrap();
+    let a: Vec<String> = a.iter().map(|s| s.to_string()).collect();
+    let params = Params::parse(&a, d.params).unwrap();
+    let text = std::fs::read_to_string(d.test_path()).unwrap();
+
+    (d.image)(&text, &params).unwrap()
+}
+
+fn count(image: &Image, rgb: image::Rgb) -> usize {
+    image.iter().filter(|&&c| c == rgb).count()
+}
+
+#[test]
+fn days() {
+    let vents = day5::parse(&std::fs::read_to_string("src/5/test.txt").unwrap()).unwrap();
+    assert_eq!(
+        day5::vent_map(&vents, 10, false)
+            .iter()
+            .filter(|&&n| n >= 2)
+            .count(),
+        5
+    );
+    assert_eq!(
+        day5::vent_map(&vents, 10, true)
+            .iter()
+            .filter(|&&n| n >= 2)
+            .count(),
+        12
+    );
+    assert_eq!(picture(5, &["size=10"]).unwrap().len(), 100);
+
+    let basins = picture(9, &[]).unwrap();
+    assert_eq!(basins[(2, 0)], image::BLACK);
+    assert_eq!(basins[(0, 0)], basins[(1, 0)]);
+    assert_ne!(basins[(0, 0)], basins[(9, 0)]);
+
+    let square = picture(13, &[]).unwrap();
+    assert_eq!((square.width(), square.height()), (5, 5));
+    assert_eq!(count(&square, image::WHITE), 16);
+
+    let path = picture(15, &["tiles=1"]).unwrap();
+    assert_eq!(count(&path, image::RED), 19);
+
+    let enhanced = picture(20, &["steps2=2"]).unwrap();
+    assert_eq!(count(&enhanced, image::WHITE), 35);
+
+    assert_eq!(picture(1, &[]), None);
+}
@@ -0,0 +1,96 @@
+//! Checks that input without answers is rejected by the parsers or answered
+//! with an error, instead of making the solvers panic.
+
+use aoc2021::*;
+
+#[test]
+fn day8_undecodable() {
+    let e = day8::parse("abc abc abc abc abc abc abc abc abc abc | abc abc abc abc\n").unwrap_err();
+    assert_eq!((e.line, e.column), (1, 1));
+    assert_eq!(e.found, "no pattern of 2 segments");
+
+    let line = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb ga cgeb";
+    let e = day8::parse(line).unwrap_err();
+    assert_eq!((e.column, e.found.as_str()), (76, "'ga'"));
+
+    let line = line.replace(" ga ", " cefbgd ");
+    assert_eq!(day8::part2(&day8::parse(&line).unwrap()), 8394);
+}
+
+#[test]
+fn day4_no_winner() {
+    let e = day4::parse("1\n").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (2, "a board"));
+
+    let board = "\n1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n";
+    let bingo = day4::parse(&format!("1,6,11,16\n{}", board)).unwrap();
+    assert_eq!(day4::part1(&bingo), Err(day4::NoWinner));
+
+    // the board wins with the last number drawn
+    let bingo = day4::parse(&format!("1,6,11,16,21\n{}", board)).unwrap();
+    assert_eq!(day4::part1(&bingo), Ok((325 - 55) * 21));
+}
+
+#[test]
+fn day10_unmatched() {
+    let lines = day10::parse(")\n(]\n<>\n").unwrap();
+    assert_eq!(day10::part1(&lines), 3 + 57);
+    assert_eq!(day10::part2(&lines), Err(day10::Unscored::NoneIncomplete));
+    assert_eq!(day10::part2(&[]), Err(day10::Unscored::NoneIncomplete));
+
+    let lines = day10::parse(&format!(")\n{}\n", "<".repeat(28))).unwrap();
+    assert_eq!(
+        day10::part2(&lines).unwrap_err().to_string(),
+        "the completion score of line 2 overflows u64"
+    );
+}
+
+#[test]
+fn day14_missing_rules() {
+    let e = day14::parse("AB\n\nAB -> C\n").unwrap_err();
+    assert_eq!(e.line, 4);
+    assert_eq!(
+        e.expected,
+        "a rule for AC, which the rule on line 3 produces"
+    );
+
+    let e = day14::parse("ABA\n\nAB -> A\nAA -> A\n").unwrap_err();
+    assert_eq!(e.expected, "a rule for BA, which is in the template");
+
+    // rules for pairs that never occur aren't needed
+    assert!(day14::parse("AA\n\nAA -> A\nZZ -> A\n").is_ok());
+    assert!(day14::parse("A\n\n").is_ok());
+}
+
+#[test]
+fn day17_target_area() {
+    let e = day17::parse("target area: x=10..5, y=-10..-5\n").unwrap_err();
+    assert_eq!(
+        (e.column, e.expected.as_str()),
+        (20, "a number of at least 10")
+    );
+
+    let e = day17::parse("target area: x=-10..-5, y=-10..-5\n").unwrap_err();
+    assert_eq!(
+        (e.column, e.expected.as_str()),
+        (16, "a number of at least 1")
+    );
+
+    let e = day17::parse("target area: x=10..20, y=-5..5\n").unwrap_err();
+    assert_eq!(
+        (e.column, e.expected.as_str()),
+        (30, "a number of at most -1")
+    );
+
+    assert!(day17::parse("target area: x=20..30, y=-10..-5\n").is_ok());
+}
+
+#[test]
+fn day11_unsynchronized() {
+    let cavern = day11::parse(&std::fs::read_to_string("src/11/test.txt").unwrap()).unwrap();
+    assert_eq!(day11::synchronized(&cavern, 195), Ok(195));
+    assert_eq!(
+        day11::synchronized(&cavern, 194),
+        Err(day11::Unsynchronized { steps: 194 })
+    );
+}
@@ -0,0 +1,28 @@
+//! Checks that day 16 rejects operator packets without the subpackets their
+//! type needs.
+
+use aoc2021::day16;
+
+#[test]
+fn comparisons_need_two_subpackets() {
+    // greater than with the literals 1 and 1
+    let packets = day16::parse("1600840881\n").unwrap();
+    assert_eq!(day16::part2(&packets[0]), 0);
+
+    // greater than with three literals
+    let e = day16::parse("1600840881\n1600C40881102\n").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "2:1: expected 2 subpackets in a packet of type 5, found 3 subpackets"
+    );
+}
+
+#[test]
+fn operators_need_subpackets() {
+    // a sum of nothing
+    let e = day16::parse("02000\n").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "1:1: expected subpackets in a packet of type 0, found 0 subpackets"
+    );
+}
@@ -0,0 +1,105 @@
+//! Checks parsing of `--param` assignments and solving with changed
+//! parameters.
+
+use aoc2021::days;
+use aoc2021::params::{self, Param, Params};
+
+const STEPS: Param = Param {
+    name: "steps",
+    default: 10,
+    min: 0,
+    max: 100,
+    help: "",
+};
+
+const SIZE: Param = Param {
+    name: "size",
+    default: 5,
+    min: 1,
+    max: 9,
+    help: "",
+};
+
+fn assignments(a: &[&str]) -> Vec<String> {
+    a.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn defaults_and_assignments() {
+    let params = Params::parse(&assignments(&["steps=3", "steps=7"]), &[STEPS, SIZE]).unwrap();
+
+    assert_eq!(params.get(&STEPS), 7);
+    assert_eq!(params.get(&SIZE), 5);
+    assert_eq!(Params::default().get(&STEPS), 10);
+}
+
+#[test]
+fn invalid_assignments() {
+    let e = Params::parse(&assignments(&["width=3"]), &[STEPS, SIZE]).unwrap_err();
+    assert_eq!(e, "unknown parameter width, expected one of: steps, size");
+
+    let e = Params::parse(&assignments(&["steps=3"]), &[]).unwrap_err();
+    assert_eq!(e, "unknown parameter steps, the day has no parameters");
+
+    let e = Params::parse(&assignments(&["steps"]), &[STEPS]).unwrap_err();
+    assert_eq!(e, "expected name=value, found steps");
+
+    let e = Params::parse(&assignments(&["steps=-1"]), &[STEPS]).unwrap_err();
+    assert_eq!(e, "invalid value for steps: -1");
+
+    let e = Params::parse(&assignments(&["size=0"]), &[SIZE]).unwrap_err();
+    assert_eq!(e, "invalid value for size: 0, expected 1 to 9");
+
+    let e = Params::parse(&assignments(&["steps=4294967296"]), &[STEPS]).unwrap_err();
+    assert_eq!(e, "invalid value for steps: 4294967296, expected 0 to 100");
+
+    assert!(Params::parse(&assignments(&["steps=0", "steps=100"]), &[STEPS]).is_ok());
+
+    // an empty map has no path through it
+    let tiles = days::find(15).unwrap().params;
+    let e = Params::parse(&assignments(&["tiles=0"]), tiles).unwrap_err();
+    assert_eq!(e, "invalid value for tiles: 0, expected 1 to 50");
+}
+
+#[test]
+fn take_repeated_params() {
+    let mut args = assignments(&["6", "--param", "days1=18", "-p", "days2=80"]);
+
+    assert_eq!(
+        params::take_params(&mut args).unwrap(),
+        ["days1=18", "days2=80"]
+    );
+    assert_eq!(args, ["6"]);
+}
+
+/// Solves both parts of a day on its sample with the given assignments.
+fn solve(day: u32, a: &[&str]) -> Vec<String> {
+    let d = days::find(day).unwrap();
+    let params = Params::parse(&assignments(a), d.params).unwrap();
+    let text = std::fs::read_to_string(d.test_path()).unwrap();
+
+    (d.solve)(&text, &[1, 2], &params)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.answer.unwrap())
+        .collect()
+}
+
+#[test]
+fn changed_params() {
+    // the examples from the puzzle text for fewer days and steps
+    assert_eq!(solve(6, &["days1=18", "days2=80"]), ["26", "5934"]);
+    assert_eq!(solve(11, &["steps=10"]), ["204", "195"]);
+    assert_eq!(solve(14, &["steps1=0", "steps2=10"]), ["1", "1588"]);
+    assert_eq!(solve(15, &["tiles=1"]), ["40", "40"]);
+    assert_eq!(solve(20, &["steps1=0", "steps2=2"]), ["10", "35"]);
+}
+
+#[test]
+fn largest_params() {
+    // the losing score times the rolls doesn't fit in i32
+    assert_eq!(
+        solve(21, &["score1=1000000", "score2=1"]),
+        ["749989750035", "27"]
+    );
+}
@@ -0,0 +1,31 @@
+use aoc2021::pool;
+
+#[test]
+fn map_keeps_order() {
+    let items: Vec<u64> = (0..100).collect();
+
+    for threads in [1, 3, 200] {
+        let squares: Vec<u64> = pool::map(&items, threads, |&n| n * n)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn map_reports_panics() {
+    let results = pool::map(&[1, 0, 2], 2, |&n| {
+        if n == 0 {
+            panic!("division by zero");
+        }
+        10 / n
+    });
+
+    assert_eq!(
+        results,
+        [Ok(10), Err(String::from("division by zero")), Ok(5)]
+    );
+    assert!(pool::map(&[] as &[u32], 4, |&n| n).is_empty());
+}
@@ -0,0 +1,137 @@
+//! Differential tests that check the optimized solvers against naive
+//! reference implementations on random small inputs.
+
+use aoc2021::rng::Rng;
+use aoc2021::space::{Cuboid, Point3};
+use aoc2021::{day12, day13, day14, day22, day6};
+use std::fmt::Debug;
+
+const CASES: u64 = 200;
+
+/// Checks `property` on `CASES` random inputs. A failing case panics with its
+/// seed and input, so it can be reproduced with `Rng::new(seed)`.
+fn forall<T: Debug>(generate: impl Fn(&mut Rng) -> T, property: impl Fn(&T) -> bool) {
+    for seed in 0..CASES {
+        let input = generate(&mut Rng::new(seed));
+        assert!(
+            property(&input),
+            "property failed for seed {} on {:?}",
+            seed,
+            input
+        );
+    }
+}
+
+#[test]
+fn day6_buckets_match_fish_list() {
+    forall(
+        |rng| {
+            let timers: Vec<u8> = (0..rng.between(1, 10))
+                .map(|_| rng.between(0, 8) as u8)
+                .collect();
+            (timers, rng.below(60))
+        },
+        |(timers, days)| {
+            let text: Vec<String> = timers.iter().map(|t| t.to_string()).collect();
+            let population = day6::parse(&text.join(",")).unwrap();
+
+            day6::simulate(&population, *days) == Ok(day6::simulate_naive(timers, *days) as u64)
+        },
+    );
+}
+
+#[test]
+fn day12_memo_matches_walking_paths() {
+    forall(
+        |rng| {
+            let size = rng.between(1, 10) as usize;
+            day12::generate(rng, size)
+        },
+        |text| {
+            let caves = day12::parse(text).unwrap();
+
+            [false, true]
+                .iter()
+                .all(|&twice| day12::paths(&caves, twice) == day12::paths_naive(&caves, twice))
+        },
+    );
+}
+
+#[test]
+fn day13_folding_reads_unfolded_letters() {
+    forall(
+        |rng| {
+            let letters: String = (0..rng.between(1, 8))
+                .map(|_| *rng.choose(&['A', 'E', 'G', 'I', 'J', 'L', 'R', 'Z']))
+                .collect();
+            let folds = rng.between(1, 12) as usize;
+            (day13::unfold(rng, &letters, folds), letters)
+        },
+        |(text, letters)| {
+            let manual = day13::parse(text).unwrap();
+            day13::read(&day13::fold_all(&manual)).as_ref() == Some(letters)
+        },
+    );
+}
+
+#[test]
+fn day14_pair_counts_match_expansion() {
+    forall(
+        |rng| {
+            let elements = &['A', 'B', 'C', 'D'][..rng.between(1, 4) as usize];
+
+            let mut text: String = (0..rng.between(1, 8))
+                .map(|_| *rng.choose(elements))
+                .collect();
+            text += "\n\n";
+            for a in elements {
+                for b in elements {
+                    text += &format!("{}{} -> {}\n", a, b, rng.choose(elements));
+                }
+            }
+
+            (text, rng.below(9))
+        },
+        |(text, steps)| {
+            let (mut polymer, ruleset) = day14::parse(text).unwrap();
+            let template = text.lines().next().unwrap();
+            let expanded = day14::expand_naive(template, &ruleset, *steps);
+
+            for _ in 0..*steps {
+                polymer = polymer.step(&ruleset).unwrap();
+            }
+
+            polymer.len() == Some(expanded.len() as u64)
+                && "ABCD"
+                    .chars()
+                    .all(|c| polymer.count(c) == expanded.matches(c).count() as u64)
+        },
+    );
+}
+
+#[test]
+fn day22_signed_counts_match_cubes() {
+    forall(
+        |rng| {
+            (0..rng.between(1, 12))
+                .map(|_| {
+                    let [a, b] = [0; 2].map(|_| {
+                        Point3::new(rng.between(-8, 8), rng.between(-8, 8), rng.between(-8, 8))
+                    });
+
+                    day22::Step {
+                        on: rng.chance(0.6),
+                        cuboid: Cuboid::new(a.zip_with(b, i64::min), a.zip_with(b, i64::max)),
+                    }
+                })
+                .collect::<Vec<_>>()
+        },
+        |steps| {
+            let everything = Cuboid::around_origin(8);
+
+            day22::reboot(steps) == day22::reboot_naive(steps, &everything) as i64
+                && day22::part1(steps, 4)
+                    == day22::reboot_naive(steps, &Cuboid::around_origin(4)) as i64
+        },
+    );
+}
@@ -0,0 +1,59 @@
+use aoc2021::report::{self, Format, Record};
+use aoc2021::solution::Solved;
+use std::time::Duration;
+
+#[test]
+fn take_format() {
+    let mut args = vec![String::from("--format"), String::from("json")];
+    assert_eq!(report::take_format(&mut args), Ok(Format::Json));
+    assert!(args.is_empty());
+
+    assert_eq!(report::take_format(&mut Vec::new()), Ok(Format::Text));
+
+    let mut args = vec![String::from("--format"), String::from("xml")];
+    assert!(report::take_format(&mut args).is_err());
+}
+
+#[test]
+fn json_records() {
+    let records = [
+        Record {
+            day: 16,
+            input: String::from("src/16/test.txt"),
+            outcome: Ok(Solved {
+                part: 1,
+                answer: Ok(String::from("16,12")),
+                elapsed: Duration::from_nanos(1500),
+            }),
+        },
+        Record {
+            day: 6,
+            input: String::from("-"),
+            outcome: Ok(Solved {
+                part: 2,
+                answer: Err(String::from("the counts overflow u64 in step 490")),
+                elapsed: Duration::from_nanos(800),
+            }),
+        },
+        Record {
+            day: 5,
+            input: String::from("-"),
+            outcome: Err(String::from("1:1: expected a number, found \"x\"")),
+        },
+    ];
+
+    assert_eq!(
+        report::json(&records),
+        "[\n  \
+         {\"day\": 16, \"part\": 1, \"answer\": \"16,12\", \"input\": \"src/16/test.txt\", \"elapsed_ns\": 1500},\n  \
+         {\"day\": 6, \"part\": 2, \"input\": \"-\", \"error\": \"the counts overflow u64 in step 490\"},\n  \
+         {\"day\": 5, \"input\": \"-\", \"error\": \"1:1: expected a number, found \\\"x\\\"\"}\n\
+         ]\n"
+    );
+    assert_eq!(report::json(&[]), "[]\n");
+}
+
+#[test]
+fn json_string_escapes() {
+    assert_eq!(report::json_string("a\\b\n\u{1}"), "\"a\\\\b\\n\\u0001\"");
+}
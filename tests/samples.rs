@@ -0,0 +1,369 @@
+//! Checks every day against the examples from the puzzle text in
+//! `src/N/test.txt` and against the recorded answers for `src/N/data.txt`.
+
+use aoc2021::params::Params;
+use aoc2021::solution::Solution;
+use aoc2021::*;
+use std::fmt::Debug;
+
+use day1::Day1;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use day13::Day13;
+use day14::Day14;
+use day15::Day15;
+use day16::Day16;
+use day17::Day17;
+use day18::Day18;
+use day19::Day19;
+use day2::Day2;
+use day20::Day20;
+use day21::Day21;
+use day22::Day22;
+use day3::Day3;
+use day4::Day4;
+use day5::Day5;
+use day6::Day6;
+use day7::Day7;
+use day8::Day8;
+use day9::Day9;
+
+/// Solves both parts of a day on `src/N/<file>`.
+fn check<S>(file: &str, part1: S::Answer1, part2: S::Answer2)
+where
+    S: Solution,
+    S::Answer1: PartialEq + Debug,
+    S::Answer2: PartialEq + Debug,
+{
+    let path = format!("src/{}/{}", S::DAY, file);
+    let input = S::parse(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+    assert_eq!(S::part1(&input, &Params::default()), part1, "{}", path);
+    assert_eq!(S::part2(&input, &Params::default()), part2, "{}", path);
+}
+
+#[test]
+fn day1_sample() {
+    check::<Day1>("test.txt", 7, 5);
+}
+
+#[test]
+fn day1_data() {
+    check::<Day1>("data.txt", 1121, 1065);
+}
+
+#[test]
+fn day2_sample() {
+    check::<Day2>("test.txt", 150, 900);
+}
+
+#[test]
+fn day2_data() {
+    check::<Day2>("data.txt", 2039256, 1856459736);
+}
+
+#[test]
+fn day3_sample() {
+    check::<Day3>("test.txt", 198, 230);
+}
+
+#[test]
+fn day3_data() {
+    check::<Day3>("data.txt", 852500, 1007985);
+}
+
+#[test]
+fn day4_sample() {
+    check::<Day4>("test.txt", Ok(4512), Ok(1924));
+}
+
+#[test]
+fn day4_data() {
+    check::<Day4>("data.txt", Ok(6592), Ok(31755));
+}
+
+#[test]
+fn day5_sample() {
+    check::<Day5>("test.txt", 5, 12);
+}
+
+#[test]
+fn day5_data() {
+    check::<Day5>("data.txt", 5145, 16518);
+}
+
+#[test]
+fn day6_sample() {
+    check::<Day6>("test.txt", Ok(5934), Ok(26984457539));
+}
+
+#[test]
+fn day6_data() {
+    check::<Day6>("data.txt", Ok(396210), Ok(1770823541496));
+}
+
+#[test]
+fn day7_sample() {
+    check::<Day7>("test.txt", 37, 168);
+}
+
+#[test]
+fn day7_data() {
+    check::<Day7>("data.txt", 353800, 98119739);
+}
+
+#[test]
+fn day8_sample() {
+    check::<Day8>("test.txt", 26, 61229);
+}
+
+#[test]
+fn day8_data() {
+    check::<Day8>("data.txt", 521, 1016804);
+}
+
+#[test]
+fn day9_sample() {
+    check::<Day9>("test.txt", 15, 1134);
+}
+
+#[test]
+fn day9_data() {
+    check::<Day9>("data.txt", 588, 964712);
+}
+
+#[test]
+fn day10_sample() {
+    check::<Day10>("test.txt", 26397, Ok(288957));
+}
+
+#[test]
+fn day10_data() {
+    check::<Day10>("data.txt", 271245, Ok(1685293086));
+}
+
+#[test]
+fn day11_sample() {
+    check::<Day11>("test.txt", 1656, Ok(195));
+}
+
+#[test]
+fn day11_data() {
+    check::<Day11>("data.txt", 1667, Ok(488));
+}
+
+#[test]
+fn day12_sample() {
+    // the two smaller examples
+    let small = day12::parse("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end\n").unwrap();
+    assert_eq!((day12::part1(&small), day12::part2(&small)), (10, 36));
+
+    let medium = day12::parse(
+        "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\n\
+         LN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc\n",
+    )
+    .unwrap();
+    assert_eq!((day12::part1(&medium), day12::part2(&medium)), (19, 103));
+
+    check::<Day12>("test.txt", 226, 3509);
+}
+
+#[test]
+fn day13_sample() {
+    // the square isn't a letter, so part 2 is the sheet
+    check::<Day13>(
+        "test.txt",
+        17,
+        String::from("#####\n#...#\n#...#\n#...#\n#####\n"),
+    );
+}
+
+#[test]
+fn day14_sample() {
+    check::<Day14>("test.txt", Ok(1588), Ok(2188189693529));
+}
+
+#[test]
+fn day14_data() {
+    check::<Day14>("data.txt", Ok(3831), Ok(5725739914282));
+}
+
+#[test]
+fn day15_sample() {
+    check::<Day15>("test.txt", 40, 315);
+}
+
+#[test]
+fn day15_data() {
+    check::<Day15>("data.txt", 626, 2966);
+}
+
+#[test]
+fn day16_sample() {
+    // The version sums are only given for these examples
+    let packets = day16::parse(
+        "8A004A801A8002F478\n\
+         620080001611562C8802118E34\n\
+         C0015000016115A2E0802F182340\n\
+         A0016C880162017C3686B18A3D4780\n",
+    )
+    .unwrap();
+    let sums: Vec<u32> = packets.iter().map(day16::part1).collect();
+    assert_eq!(sums, [16, 12, 23, 31]);
+
+    check::<Day16>(
+        "test.txt",
+        String::from("14,8,15,11,13,19,16,20"),
+        String::from("3,54,7,9,1,0,0,1"),
+    );
+}
+
+#[test]
+fn day16_data() {
+    check::<Day16>(
+        "data.txt",
+        String::from("969"),
+        String::from("124921618408"),
+    );
+}
+
+#[test]
+fn day17_sample() {
+    check::<Day17>("test.txt", 45, 112);
+}
+
+#[test]
+fn day17_data() {
+    check::<Day17>("data.txt", 6786, 2313);
+}
+
+#[test]
+fn day18_sample() {
+    check::<Day18>("test.txt", 4140, 3993);
+}
+
+#[test]
+fn day19_sample() {
+    check::<Day19>("test.txt", Ok(79), Ok(3621));
+}
+
+#[test]
+fn day20_sample() {
+    check::<Day20>("test.txt", 35, 3351);
+}
+
+#[test]
+fn day20_data() {
+    check::<Day20>("data.txt", 5486, 20210);
+}
+
+#[test]
+fn day21_sample() {
+    check::<Day21>("test.txt", 739785, Ok(444356092776315));
+}
+
+#[test]
+fn day21_data() {
+    check::<Day21>("data.txt", 752745, Ok(309196008717909));
+}
+
+#[test]
+fn day22_sample() {
+    // the larger example only gives the answer of part 1
+    let larger = day22::parse(
+        "on x=-20..26,y=-36..17,z=-47..7\n\
+         on x=-20..33,y=-21..23,z=-26..28\n\
+         on x=-22..28,y=-29..23,z=-38..16\n\
+         on x=-46..7,y=-6..46,z=-50..-1\n\
+         on x=-49..1,y=-3..46,z=-24..28\n\
+         on x=2..47,y=-22..22,z=-23..27\n\
+         on x=-27..23,y=-28..26,z=-21..29\n\
+         on x=-39..5,y=-6..47,z=-3..44\n\
+         on x=-30..21,y=-8..43,z=-13..34\n\
+         on x=-22..26,y=-27..20,z=-29..19\n\
+         off x=-48..-32,y=26..41,z=-47..-37\n\
+         on x=-12..35,y=6..50,z=-50..-2\n\
+         off x=-48..-32,y=-32..-16,z=-15..-5\n\
+         on x=-18..26,y=-33..15,z=-7..46\n\
+         off x=-40..-22,y=-38..-28,z=23..41\n\
+         on x=-16..35,y=-41..10,z=-47..6\n\
+         off x=-32..-23,y=11..30,z=-14..3\n\
+         on x=-49..-5,y=-3..45,z=-29..18\n\
+         off x=18..30,y=-20..-8,z=-3..13\n\
+         on x=-41..9,y=-7..43,z=-33..15\n\
+         on x=-54112..-39298,y=-85059..-49293,z=-27449..7877\n\
+         on x=967..23432,y=45373..81175,z=27513..53682\n",
+    )
+    .unwrap();
+    assert_eq!(day22::part1(&larger, 50), 590784);
+
+    // the example of part 2
+    let reboot = day22::parse(
+        "on x=-5..47,y=-31..22,z=-19..33\n\
+         on x=-44..5,y=-27..21,z=-14..35\n\
+         on x=-49..-1,y=-11..42,z=-10..38\n\
+         on x=-20..34,y=-40..6,z=-44..1\n\
+         off x=26..39,y=40..50,z=-2..11\n\
+         on x=-41..5,y=-41..6,z=-36..8\n\
+         off x=-43..-33,y=-45..-28,z=7..25\n\
+         on x=-33..15,y=-32..19,z=-34..11\n\
+         off x=35..47,y=-46..-34,z=-11..5\n\
+         on x=-14..36,y=-6..44,z=-16..29\n\
+         on x=-57795..-6158,y=29564..72030,z=20435..90618\n\
+         on x=36731..105352,y=-21140..28532,z=16094..90401\n\
+         on x=30999..107136,y=-53464..15513,z=8553..71215\n\
+         on x=13528..83982,y=-99403..-27377,z=-24141..23996\n\
+         on x=-72682..-12347,y=18159..111354,z=7391..80950\n\
+         on x=-1060..80757,y=-65301..-20884,z=-103788..-16709\n\
+         on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856\n\
+         on x=-52752..22273,y=-49450..9096,z=54442..119054\n\
+         on x=-29982..40483,y=-108474..-28371,z=-24328..38471\n\
+         on x=-4958..62750,y=40422..118853,z=-7672..65583\n\
+         on x=55694..108686,y=-43367..46958,z=-26781..48729\n\
+         on x=-98497..-18186,y=-63569..3412,z=1232..88485\n\
+         on x=-726..56291,y=-62629..13224,z=18033..85226\n\
+         on x=-110886..-34664,y=-81338..-8658,z=8914..63723\n\
+         on x=-55829..24974,y=-16897..54165,z=-121762..-28058\n\
+         on x=-65152..-11147,y=22489..91432,z=-58782..1780\n\
+         on x=-120100..-32970,y=-46592..27473,z=-11695..61039\n\
+         on x=-18631..37533,y=-124565..-50804,z=-35667..28308\n\
+         on x=-57817..18248,y=49321..117703,z=5745..55881\n\
+         on x=14781..98692,y=-1341..70827,z=15753..70151\n\
+         on x=-34419..55919,y=-19626..40991,z=39015..114138\n\
+         on x=-60785..11593,y=-56135..2999,z=-95368..-26915\n\
+         on x=-32178..58085,y=17647..101866,z=-91405..-8878\n\
+         on x=-53655..12091,y=50097..105568,z=-75335..-4862\n\
+         on x=-111166..-40997,y=-71714..2688,z=5609..50954\n\
+         on x=-16602..70118,y=-98693..-44401,z=5197..76897\n\
+         on x=16383..101554,y=4615..83635,z=-44907..18747\n\
+         off x=-95822..-15171,y=-19987..48940,z=10804..104439\n\
+         on x=-89813..-14614,y=16069..88491,z=-3297..45228\n\
+         on x=41075..99376,y=-20427..49978,z=-52012..13762\n\
+         on x=-21330..50085,y=-17944..62733,z=-112280..-30197\n\
+         on x=-16478..35915,y=36008..118594,z=-7885..47086\n\
+         off x=-98156..-27851,y=-49952..43171,z=-99005..-8456\n\
+         off x=2032..69770,y=-71013..4824,z=7471..94418\n\
+         on x=43670..120875,y=-42068..12382,z=-24787..38892\n\
+         off x=37514..111226,y=-45862..25743,z=-16714..54663\n\
+         off x=25699..97951,y=-30668..59918,z=-15349..69697\n\
+         off x=-44271..17935,y=-9516..60759,z=49131..112598\n\
+         on x=-61695..-5813,y=40978..94975,z=8655..80240\n\
+         off x=-101086..-9439,y=-7088..67543,z=33935..83858\n\
+         off x=18020..114017,y=-48931..32606,z=21474..89843\n\
+         off x=-77139..10506,y=-89994..-18797,z=-80..59318\n\
+         off x=8476..79288,y=-75520..11602,z=-96624..-24783\n\
+         on x=-47488..-1262,y=24338..100707,z=16292..72967\n\
+         off x=-84341..13987,y=2429..92914,z=-90671..-1318\n\
+         off x=-37810..49457,y=-71013..-7894,z=-105357..-13188\n\
+         off x=-27365..46395,y=31009..98017,z=15428..76570\n\
+         off x=-70369..-16548,y=22648..78696,z=-1892..86821\n\
+         on x=-53470..21291,y=-120233..-33476,z=-44150..38147\n\
+         off x=-93533..-4276,y=-16170..68771,z=-104985..-24507\n",
+    )
+    .unwrap();
+    assert_eq!(day22::part1(&reboot, 50), 474140);
+    assert_eq!(day22::part2(&reboot), 2758514936282235);
+
+    check::<Day22>("test.txt", 39, 39);
+}
@@ -0,0 +1,148 @@
+//! Checks that adding a day registers it everywhere in order.
+
+use aoc2021::scaffold::{self, File};
+
+fn read(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+/// A repository with days 2, 11 and 14.
+fn fixture(path: &str) -> Result<String, String> {
+    let days = [2, 11, 14];
+    let text = match path {
+        "Cargo.toml" => days
+            .iter()
+            .map(|d| format!("[[bin]]\nname = \"{0}\"\npath = \"src/{0}/main.rs\"\n\n", d))
+            .chain(["[[bin]]\nname = \"aoc\"\npath = \"src/aoc/main.rs\"\n".to_string()])
+            .collect(),
+        "src/lib.rs" => ["11", "14", "2"]
+            .iter()
+            .map(|d| format!("#[path = \"{0}/mod.rs\"]\npub mod day{0};\n", d))
+            .collect(),
+        "src/days.rs" => {
+            let list: String = days
+                .iter()
+                .map(|d| format!("    day::<day{0}::Day{0}>(),\n", d))
+                .collect();
+            format!("pub const DAYS: &[Day] = &[\n{}];\n", list)
+        }
+        "tests/samples.rs" => {
+            let uses: String = ["11", "14", "2"]
+                .iter()
+                .map(|d| format!("use day{0}::Day{0};\n", d))
+                .collect();
+            let tests: Vec<String> = days
+                .iter()
+                .map(|d| {
+                    format!(
+                        "#[test]\nfn day{0}_data() {{\n    check::<Day{0}>();\n}}\n",
+                        d
+                    )
+                })
+                .collect();
+            format!("{}\n{}", uses, tests.join("\n"))
+        }
+        "answers.toml" => {
+            let tables: Vec<String> = days
+                .iter()
+                .flat_map(|d| {
+                    ["test.txt", "data.txt"].map(|input| {
+                        format!("[[answer]]\nday = {0}\ninput = \"src/{0}/{1}\"\n", d, input)
+                    })
+                })
+                .collect();
+            tables.join("\n")
+        }
+        _ => return Err(format!("no {}", path)),
+    };
+
+    Ok(text)
+}
+
+fn file<'a>(files: &'a [File], path: &str) -> &'a str {
+    &files.iter().find(|f| f.path == path).unwrap().contents
+}
+
+/// The position of `s` in `text`, which has to contain it once.
+fn position(text: &str, s: &str) -> usize {
+    assert_eq!(text.matches(s).count(), 1, "{}", s);
+    text.find(s).unwrap()
+}
+
+#[test]
+fn new_day() {
+    let files = scaffold::files(12, "Passage Pathing", fixture).unwrap();
+
+    let module = file(&files, "src/12/mod.rs");
+    assert!(module.starts_with("//! Day 12: Passage Pathing\n"));
+    assert!(module.contains("impl Solution for Day12 {"));
+    assert!(!module.contains("todo!()"));
+    assert!(file(&files, "src/12/main.rs").contains("solution::main::<Day12>();"));
+    assert_eq!(file(&files, "src/12/data.txt"), "");
+
+    let cargo = file(&files, "Cargo.toml");
+    let bin = "[[bin]]\nname = \"12\"\npath = \"src/12/main.rs\"\n\n";
+    assert!(position(cargo, "name = \"11\"") < position(cargo, bin));
+    assert!(position(cargo, bin) < position(cargo, "name = \"14\""));
+
+    let lib = file(&files, "src/lib.rs");
+    assert!(position(lib, "pub mod day11;") < position(lib, "pub mod day12;"));
+    assert!(position(lib, "pub mod day12;") < position(lib, "pub mod day14;"));
+    assert!(position(lib, "pub mod day14;") < position(lib, "pub mod day2;"));
+
+    let days = file(&files, "src/days.rs");
+    assert!(position(days, "day11::Day11>") < position(days, "day12::Day12>"));
+    assert!(position(days, "day12::Day12>") < position(days, "day14::Day14>"));
+
+    let samples = file(&files, "tests/samples.rs");
+    assert!(position(samples, "use day11::") < position(samples, "use day12::"));
+    assert!(position(samples, "fn day11_data") < position(samples, "fn day12_sample"));
+    assert!(position(samples, "fn day12_sample") < position(samples, "fn day14_data"));
+    assert!(!samples.contains("fn day12_data"));
+
+    let answers = file(&files, "answers.toml");
+    let table = "[[answer]]\nday = 12\ninput = \"src/12/test.txt\"\n\n";
+    assert!(position(answers, "src/11/data.txt") < position(answers, table));
+    assert!(position(answers, table) < position(answers, "src/14/test.txt"));
+    assert!(!answers.contains("src/12/data.txt"));
+    assert!(aoc2021::answers::parse(answers).is_ok());
+}
+
+#[test]
+fn last_day() {
+    let files = scaffold::files(25, "", read).unwrap();
+
+    assert!(file(&files, "src/25/mod.rs").starts_with("//! Day 25\n"));
+    assert!(file(&files, "src/days.rs").contains("    day::<day25::Day25>(),\n];"));
+    assert!(file(&files, "tests/samples.rs").ends_with(
+        "}\n\n\
+         #[test]\n\
+         fn day25_sample() {\n    \
+             check::<Day25>(\"test.txt\", 0, 0);\n\
+         }\n"
+    ));
+    assert!(file(&files, "answers.toml").ends_with(
+        "\"\n\n\
+         [[answer]]\n\
+         day = 25\n\
+         input = \"src/25/test.txt\"\n"
+    ));
+
+    // the modules of day 25 come after day 21 and before day 3
+    let lib = file(&files, "src/lib.rs");
+    assert!(position(lib, "pub mod day21;") < position(lib, "pub mod day25;"));
+    assert!(position(lib, "pub mod day25;") < position(lib, "pub mod day3;"));
+}
+
+#[test]
+fn existing_day() {
+    let e = scaffold::files(7, "", read).unwrap_err();
+    assert_eq!(e, "day 7 is already registered in Cargo.toml");
+
+    assert!(scaffold::files(0, "", read).is_err());
+    assert!(scaffold::files(26, "", read).is_err());
+    assert_eq!(
+        scaffold::register_answers("# no answers\n", 3),
+        Err(String::from("no days found in answers.toml"))
+    );
+}
@@ -0,0 +1,72 @@
+use aoc2021::grid::Grid;
+use aoc2021::search;
+
+/// A weighted graph with two routes from `a` to `d`.
+fn edges(n: &char) -> Vec<(char, u32)> {
+    match n {
+        'a' => vec![('b', 1), ('c', 5)],
+        'b' => vec![('c', 1), ('d', 7)],
+        'c' => vec![('d', 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn dijkstra_finds_cheapest_path() {
+    let found = search::dijkstra(['a'], edges, |&n| n == 'd').unwrap();
+    assert_eq!(found.cost, 3);
+    assert_eq!(found.path, ['a', 'b', 'c', 'd']);
+    assert_eq!(found.expanded, 4);
+
+    assert_eq!(search::dijkstra(['d'], edges, |&n| n == 'a'), None);
+}
+
+#[test]
+fn several_starts_and_goals() {
+    let found = search::dijkstra(['a', 'c'], edges, |&n| n == 'd').unwrap();
+    assert_eq!(found.cost, 1);
+    assert_eq!(found.path, ['c', 'd']);
+
+    let found = search::dijkstra(['a'], edges, |&n| n == 'c' || n == 'd').unwrap();
+    assert_eq!(found.path, ['a', 'b', 'c']);
+}
+
+#[test]
+fn astar_expands_fewer_nodes() {
+    let grid = Grid::filled(20, 20, 1u32);
+    let goal = (19, 19);
+    let neighbors = |&p: &(usize, usize)| grid.neighbors4(p).map(|n| (n, grid[n]));
+
+    let plain = search::dijkstra([(0, 0)], neighbors, |&p| p == goal).unwrap();
+    let guided = search::astar(
+        [(0, 0)],
+        neighbors,
+        |&(x, y)| (goal.0 - x + goal.1 - y) as u32,
+        |&p| p == goal,
+    )
+    .unwrap();
+
+    assert_eq!(plain.cost, 38);
+    assert_eq!(guided.cost, 38);
+    assert_eq!(guided.path.len(), 39);
+    assert!(guided.expanded < plain.expanded);
+}
+
+#[test]
+fn bfs_counts_steps() {
+    let found = search::bfs(
+        [1u32],
+        |&n| [n + 3, n * 2].into_iter().filter(|&m| m <= 20),
+        |&n| n == 11,
+    )
+    .unwrap();
+
+    assert_eq!(found.cost, 3);
+    assert_eq!(found.path, [1, 4, 8, 11]);
+    assert!(search::bfs(
+        [1u32],
+        |&n| [n * 2].into_iter().filter(|&m| m < 100),
+        |&n| n == 3
+    )
+    .is_none());
+}
@@ -0,0 +1,197 @@
+//! Checks the snailfish numbers of day 18 against every worked example of
+//! the puzzle.
+
+use aoc2021::day18::{self, SnailNum};
+
+fn num(s: &str) -> SnailNum {
+    s.parse().unwrap()
+}
+
+fn nums(lines: &[&str]) -> Vec<SnailNum> {
+    lines.iter().map(|s| num(s)).collect()
+}
+
+#[test]
+fn display_round_trip() {
+    for s in [
+        "[1,2]",
+        "[[1,2],3]",
+        "[9,[8,7]]",
+        "[[1,9],[8,5]]",
+        "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+        "[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]",
+        "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+    ] {
+        assert_eq!(num(s).to_string(), s);
+    }
+}
+
+#[test]
+fn parse_errors() {
+    let e = "7".parse::<SnailNum>().unwrap_err();
+    assert_eq!((e.column, e.expected.as_str()), (1, "'['"));
+
+    let e = "[1,2".parse::<SnailNum>().unwrap_err();
+    assert_eq!((e.column, e.expected.as_str()), (5, "']'"));
+
+    assert!("[1,2]]".parse::<SnailNum>().is_err());
+    assert!("[[1,2]".parse::<SnailNum>().is_err());
+    assert!(day18::parse("").is_err());
+}
+
+#[test]
+fn explode() {
+    for (before, after) in [
+        ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+        ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+        ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+        (
+            "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+        ),
+        (
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+        ),
+    ] {
+        let mut n = num(before);
+        assert!(n.explode(), "{}", before);
+        assert_eq!(n.to_string(), after);
+    }
+
+    assert!(!num("[[[[0,9],2],3],4]").explode());
+}
+
+#[test]
+fn split() {
+    for (before, after) in [
+        ("[10,1]", "[[5,5],1]"),
+        ("[1,11]", "[1,[5,6]]"),
+        ("[12,0]", "[[6,6],0]"),
+    ] {
+        let mut n = num(before);
+        assert!(n.split());
+        assert_eq!(n.to_string(), after);
+    }
+
+    assert!(!num("[9,[9,9]]").split());
+}
+
+#[test]
+fn reduction_steps() {
+    let mut n = SnailNum::pair(num("[[[[4,3],4],4],[7,[[8,4],9]]]"), num("[1,1]"));
+    assert_eq!(n.to_string(), "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+
+    assert!(n.explode());
+    assert_eq!(n.to_string(), "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]");
+    assert!(n.explode());
+    assert_eq!(n.to_string(), "[[[[0,7],4],[15,[0,13]]],[1,1]]");
+    assert!(!n.clone().explode());
+    assert!(n.split());
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]");
+    assert!(n.split());
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]");
+    assert!(n.explode());
+    assert_eq!(n.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+    let reduced = n.clone();
+    n.reduce();
+    assert_eq!(n, reduced);
+
+    assert_eq!(
+        num("[[[[4,3],4],4],[7,[[8,4],9]]]") + num("[1,1]"),
+        num("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
+    );
+}
+
+#[test]
+fn sums() {
+    let mut list = nums(&["[1,1]", "[2,2]", "[3,3]", "[4,4]"]);
+    assert_eq!(
+        day18::sum(&list).to_string(),
+        "[[[[1,1],[2,2]],[3,3]],[4,4]]"
+    );
+
+    list.push(num("[5,5]"));
+    assert_eq!(
+        day18::sum(&list).to_string(),
+        "[[[[3,0],[5,3]],[4,4]],[5,5]]"
+    );
+
+    list.push(num("[6,6]"));
+    assert_eq!(
+        day18::sum(&list).to_string(),
+        "[[[[5,0],[7,4]],[5,5]],[6,6]]"
+    );
+}
+
+#[test]
+fn larger_sum() {
+    let list = nums(&[
+        "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+        "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+        "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+        "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+        "[7,[5,[[3,8],[1,4]]]]",
+        "[[2,[2,2]],[8,[8,1]]]",
+        "[2,9]",
+        "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+        "[[[5,[7,4]],7],1]",
+        "[[[[4,2],2],6],[8,7]]",
+    ]);
+
+    // the sum after adding every number
+    let partial = [
+        "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]",
+        "[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]",
+        "[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]",
+        "[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]",
+        "[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]",
+        "[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]",
+        "[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]",
+        "[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]",
+        "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+    ];
+
+    let mut sum = list[0].clone();
+    for (n, expected) in list[1..].iter().zip(partial) {
+        sum = sum + n.clone();
+        assert_eq!(sum.to_string(), expected);
+    }
+    assert_eq!(day18::sum(&list), sum);
+}
+
+#[test]
+fn magnitudes() {
+    for (s, magnitude) in [
+        ("[[1,2],[[3,4],5]]", 143),
+        ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+        ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+        ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+        ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+        (
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+            3488,
+        ),
+    ] {
+        assert_eq!(num(s).magnitude(), magnitude, "{}", s);
+    }
+}
+
+#[test]
+fn homework() {
+    let homework = day18::parse(&std::fs::read_to_string("src/18/test.txt").unwrap()).unwrap();
+
+    assert_eq!(
+        day18::sum(&homework).to_string(),
+        "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+    );
+
+    // the largest magnitude comes from the ninth and the first number
+    let best = homework[8].clone() + homework[0].clone();
+    assert_eq!(
+        best.to_string(),
+        "[[[[7,8],[6,6]],[[6,0],[7,7]]],[[[7,8],[8,8]],[[7,9],[0,6]]]]"
+    );
+    assert_eq!(best.magnitude(), day18::part2(&homework));
+}
@@ -0,0 +1,257 @@
+//! Checks the rotations and cuboids in three dimensions, and the scanner
+//! alignment of day 19 against the worked examples of the puzzle.
+
+use aoc2021::params::Params;
+use aoc2021::space::{Cuboid, Point3, Rotation};
+use aoc2021::{day19, day22, days};
+use std::collections::HashSet;
+
+fn points(s: &str) -> Vec<Point3> {
+    s.split(' ')
+        .map(|p| {
+            let c: Vec<i64> = p.split(',').map(|c| c.parse().unwrap()).collect();
+            Point3::new(c[0], c[1], c[2])
+        })
+        .collect()
+}
+
+#[test]
+fn rotations_form_a_group() {
+    let all = Rotation::all();
+    assert_eq!(all.len(), 24);
+    assert_eq!(all[0], Rotation::IDENTITY);
+
+    let distinct: HashSet<Rotation> = all.iter().copied().collect();
+    assert_eq!(distinct.len(), 24);
+
+    let p = Point3::new(1, 2, 3);
+    for a in &all {
+        assert_eq!(a.then(&a.inverse()), Rotation::IDENTITY);
+        assert_eq!(a.inverse().apply(a.apply(p)), p);
+        assert_eq!(a.apply(p).length_squared(), p.length_squared());
+
+        for b in &all {
+            let ab = a.then(b);
+            assert!(distinct.contains(&ab));
+            assert_eq!(ab.apply(p), b.apply(a.apply(p)));
+        }
+    }
+}
+
+#[test]
+fn rotations_keep_handedness() {
+    // the cross product of the rotated x and y axes is the rotated z axis
+    for r in Rotation::all() {
+        let [x, y, z] =
+            [(1, 0, 0), (0, 1, 0), (0, 0, 1)].map(|(x, y, z)| r.apply(Point3::new(x, y, z)));
+        let cross = Point3::new(
+            x.y * y.z - x.z * y.y,
+            x.z * y.x - x.x * y.z,
+            x.x * y.y - x.y * y.x,
+        );
+        assert_eq!(cross, z);
+    }
+}
+
+#[test]
+fn orientations() {
+    let seen = [
+        "-1,-1,1 -2,-2,2 -3,-3,3 -2,-3,1 5,6,-4 8,0,7",
+        "1,-1,1 2,-2,2 3,-3,3 2,-1,3 -5,4,-6 -8,-7,0",
+        "-1,-1,-1 -2,-2,-2 -3,-3,-3 -1,-3,-2 4,6,5 -7,0,8",
+        "1,1,-1 2,2,-2 3,3,-3 1,3,-2 -4,-6,5 7,0,8",
+        "1,1,1 2,2,2 3,3,3 3,1,2 -6,-4,-5 0,7,-8",
+    ]
+    .map(points);
+
+    for other in &seen[1..] {
+        let matching = Rotation::all()
+            .into_iter()
+            .filter(|r| {
+                seen[0]
+                    .iter()
+                    .map(|&p| r.apply(p))
+                    .eq(other.iter().copied())
+            })
+            .count();
+        assert_eq!(matching, 1, "{:?}", other);
+    }
+}
+
+#[test]
+fn scanner_positions() {
+    let reports = day19::parse(&std::fs::read_to_string("src/19/test.txt").unwrap()).unwrap();
+    let map = day19::align(&reports).unwrap();
+
+    let positions: Vec<Point3> = map.scanners.iter().map(|s| s.position).collect();
+    assert_eq!(
+        positions,
+        points("0,0,0 68,-1246,-43 1105,-1205,1229 -92,-2380,-20 -20,-1133,1061")
+    );
+
+    assert_eq!(map.beacons.len(), 79);
+    for beacon in points("-892,524,684 -689,845,-530 459,-707,401 -739,-1745,668") {
+        assert!(map.beacons.contains(&beacon), "{}", beacon);
+    }
+
+    // the beacons that scanners 0 and 1 both detect, relative to scanner 0
+    let shared = points(
+        "-618,-824,-621 -537,-823,-458 -447,-329,318 404,-588,-901 544,-627,-890 528,-643,409 \
+         -661,-816,-575 390,-675,-793 423,-701,434 -345,-311,381 459,-707,401 -485,-357,347",
+    );
+    let one: HashSet<Point3> = reports[1]
+        .beacons
+        .iter()
+        .map(|&b| map.scanners[1].locate(b))
+        .collect();
+    for beacon in shared {
+        assert!(reports[0].beacons.contains(&beacon) && one.contains(&beacon));
+    }
+}
+
+#[test]
+fn scans_align_once() {
+    let reports = day19::parse(&std::fs::read_to_string("src/19/test.txt").unwrap()).unwrap();
+    let scans = day19::Scans::new(reports);
+
+    // both parts get the map the scanners were aligned to the first time
+    let map = scans.map().unwrap();
+    assert!(std::ptr::eq(map, scans.map().unwrap()));
+    assert_eq!(map.beacons.len(), 79);
+}
+
+#[test]
+fn unaligned_scanner() {
+    let mut text = std::fs::read_to_string("src/19/test.txt").unwrap();
+    text += "\n--- scanner 5 ---\n1,2,3\n4,5,6\n";
+    let reports = day19::parse(&text).unwrap();
+
+    let e = day19::align(&reports).unwrap_err();
+    assert_eq!(e.scanners, [5]);
+    assert_eq!(
+        e.to_string(),
+        "scanner 5 shares 12 beacons with no placed scanner"
+    );
+
+    let d = days::find(19).unwrap();
+    let solved = (d.solve)(&text, &[1, 2], &Params::default()).unwrap();
+    for s in solved {
+        assert_eq!(
+            s.answer,
+            Err(String::from(
+                "scanner 5 shares 12 beacons with no placed scanner"
+            ))
+        );
+    }
+}
+
+#[test]
+fn scanners_in_order() {
+    assert!(day19::parse("--- scanner 1 ---\n1,2,3\n").is_err());
+    assert!(day19::parse("--- scanner 0 ---\n1,2\n").is_err());
+    assert!(day19::parse("").is_err());
+}
+
+fn cuboid(min: (i64, i64, i64), max: (i64, i64, i64)) -> Cuboid {
+    Cuboid::new(
+        Point3::new(min.0, min.1, min.2),
+        Point3::new(max.0, max.1, max.2),
+    )
+}
+
+#[test]
+fn cuboid_volume_and_contains() {
+    let c = cuboid((10, 10, 10), (12, 12, 12));
+    assert_eq!(c.volume(), 27);
+    assert_eq!(cuboid((1, 2, 3), (1, 2, 3)).volume(), 1);
+    assert_eq!(Cuboid::around_origin(50).volume(), 101 * 101 * 101);
+
+    assert!(c.contains(Point3::new(10, 12, 11)));
+    assert!(!c.contains(Point3::new(9, 11, 11)));
+    assert!(!c.contains(Point3::new(11, 11, 13)));
+
+    assert_eq!(c.to_string(), "x=10..12,y=10..12,z=10..12");
+}
+
+#[test]
+#[should_panic]
+fn cuboid_corners_in_order() {
+    cuboid((0, 5, 0), (1, 4, 1));
+}
+
+#[test]
+fn cuboid_intersection() {
+    let a = cuboid((10, 10, 10), (12, 12, 12));
+    let b = cuboid((11, 11, 11), (13, 13, 13));
+
+    assert_eq!(a.intersection(&b), Some(cuboid((11, 11, 11), (12, 12, 12))));
+    assert_eq!(b.intersection(&a), a.intersection(&b));
+    assert_eq!(a.intersection(&a), Some(a));
+
+    // touching at a corner shares a single cube
+    let corner = cuboid((12, 12, 12), (20, 20, 20));
+    assert_eq!(a.intersection(&corner).map(|c| c.volume()), Some(1));
+
+    assert_eq!(a.intersection(&cuboid((13, 10, 10), (14, 12, 12))), None);
+}
+
+#[test]
+fn cuboid_subtraction() {
+    let a = cuboid((0, 0, 0), (9, 9, 9));
+    let cases = [
+        cuboid((3, 3, 3), (5, 5, 5)),
+        cuboid((-5, 2, 4), (4, 20, 6)),
+        cuboid((9, 9, 9), (12, 12, 12)),
+        cuboid((-1, -1, -1), (10, 10, 10)),
+        cuboid((20, 0, 0), (30, 9, 9)),
+    ];
+
+    for b in cases {
+        let pieces = a.subtract(&b);
+        let overlap = a.intersection(&b).map_or(0, |c| c.volume());
+
+        assert!(pieces.len() <= 6);
+        assert_eq!(
+            pieces.iter().map(|p| p.volume()).sum::<i64>(),
+            a.volume() - overlap,
+            "{}",
+            b
+        );
+
+        // the pieces are inside `a`, outside `b` and don't overlap
+        for (i, p) in pieces.iter().enumerate() {
+            assert_eq!(p.intersection(&a), Some(*p));
+            assert_eq!(p.intersection(&b), None);
+            for q in &pieces[i + 1..] {
+                assert_eq!(p.intersection(q), None);
+            }
+        }
+    }
+
+    // a cube in the middle leaves a piece on every side
+    assert_eq!(a.subtract(&cuboid((3, 3, 3), (5, 5, 5))).len(), 6);
+    assert!(a.subtract(&cuboid((-1, -1, -1), (10, 10, 10))).is_empty());
+}
+
+#[test]
+fn reboot_steps() {
+    let step: day22::Step = "off x=-48..-32,y=26..41,z=-47..-37".parse().unwrap();
+    assert!(!step.on);
+    assert_eq!(step.cuboid, cuboid((-48, 26, -47), (-32, 41, -37)));
+    assert_eq!(step.to_string(), "off x=-48..-32,y=26..41,z=-47..-37");
+
+    let e = "on x=1..2,y=3..1,z=0..0"
+        .parse::<day22::Step>()
+        .unwrap_err();
+    assert_eq!(
+        (e.column, e.expected.as_str()),
+        (16, "a number of at least 3")
+    );
+
+    let e = "toggle x=1..2,y=1..3,z=0..0"
+        .parse::<day22::Step>()
+        .unwrap_err();
+    assert_eq!((e.column, e.expected.as_str()), (1, "'on' or 'off'"));
+
+    assert!(day22::parse("").is_err());
+}